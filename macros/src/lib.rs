@@ -0,0 +1,493 @@
+//! Procedural macros for `anylr`, re-exported by it under the `macros` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote,
+    visit::{self, Visit},
+    FnArg, GenericParam, Ident, ItemTrait, Pat, Path,
+    ReturnType, TraitItem, TraitItemFn, Type, TypePath,
+};
+
+/// Implements a trait for `Either<L, R>` whenever both `L` and `R` implement
+/// it, by forwarding every method to the side that is present.
+///
+/// Apply it to a trait definition. The trait itself is emitted unchanged,
+/// followed by the forwarding impl. Methods may take `&self`, `&mut self` or
+/// `self`, may be generic, `async` or `unsafe`, and may return `Self`, in
+/// which case the result is wrapped back into the same side. Associated types
+/// are taken from `L`, and `R` is required to agree on them.
+///
+/// With `#[anylr::delegate(both)]` the trait is also implemented for the pair
+/// `(L, R)`, calling every method on the left value and then on the right
+/// one. Such methods must return `()`, and their arguments must be `Clone`.
+///
+/// If `anylr` is re-exported under another path, pass it with
+/// `#[anylr::delegate(crate = path::to::anylr)]`.
+///
+/// Methods without a receiver, receivers such as `self: Box<Self>`,
+/// arguments of type `Self`, associated constants and generic associated
+/// types cannot be forwarded and are rejected. Supertraits have to be
+/// implemented for `Either` separately.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::Either;
+///
+/// #[anylr::delegate]
+/// trait Describe {
+///     type Out;
+///
+///     fn describe(&self) -> Self::Out;
+///     fn rename(&mut self, name: &str);
+///     fn renamed(self, name: &str) -> Self;
+///     fn pick<T: Clone>(&self, a: T, b: T) -> T;
+/// }
+///
+/// struct Cat(String);
+/// struct Dog(String);
+///
+/// impl Describe for Cat {
+///     type Out = String;
+///
+///     fn describe(&self) -> String { format!("cat {}", self.0) }
+///     fn rename(&mut self, name: &str) { self.0 = name.into() }
+///     fn renamed(self, name: &str) -> Self { Cat(name.into()) }
+///     fn pick<T: Clone>(&self, a: T, _: T) -> T { a }
+/// }
+///
+/// impl Describe for Dog {
+///     type Out = String;
+///
+///     fn describe(&self) -> String { format!("dog {}", self.0) }
+///     fn rename(&mut self, name: &str) { self.0 = name.into() }
+///     fn renamed(self, name: &str) -> Self { Dog(name.into()) }
+///     fn pick<T: Clone>(&self, _: T, b: T) -> T { b }
+/// }
+///
+/// let mut pet = Either::<Cat, Dog>::Right(Dog("Rex".into()));
+/// assert_eq!(pet.describe(), "dog Rex");
+/// pet.rename("Max");
+/// assert_eq!(pet.describe(), "dog Max");
+/// assert_eq!(pet.pick(1, 2), 2);
+/// assert_eq!(pet.renamed("Bo").describe(), "dog Bo");
+/// ```
+///
+/// Associated types may also be named with a qualified path, but `Self` may
+/// not appear inside another type:
+///
+/// ```
+/// use anylr::Either;
+///
+/// #[anylr::delegate]
+/// trait Parse {
+///     type Out;
+///
+///     fn parse(&self) -> <Self as Parse>::Out;
+/// }
+///
+/// impl Parse for &str {
+///     type Out = usize;
+///
+///     fn parse(&self) -> usize { self.len() }
+/// }
+///
+/// assert_eq!(Either::<&str, &str>::Left("four").parse(), 4);
+/// ```
+///
+/// ```compile_fail
+/// #[anylr::delegate]
+/// trait Split: Sized {
+///     fn split(self) -> Option<Self>;
+/// }
+/// ```
+///
+/// Methods without a receiver, generic associated types and associated
+/// constants are rejected too:
+///
+/// ```compile_fail
+/// #[anylr::delegate]
+/// trait Make {
+///     fn make() -> u8;
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[anylr::delegate]
+/// trait Lend {
+///     type Item<'a> where Self: 'a;
+///
+///     fn get(&self) -> u8;
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[anylr::delegate]
+/// trait Id {
+///     const ID: u8;
+///
+///     fn id(&self) -> u8;
+/// }
+/// ```
+///
+/// Broadcasting to both values of a pair:
+///
+/// ```
+/// #[anylr::delegate(both)]
+/// trait Push {
+///     fn push(&mut self, x: u8);
+/// }
+///
+/// impl Push for Vec<u8> {
+///     fn push(&mut self, x: u8) { Vec::push(self, x) }
+/// }
+///
+/// let mut pair = (Vec::new(), vec![0]);
+/// pair.push(7);
+/// assert_eq!(pair, (vec![7], vec![0, 7]));
+/// ```
+#[proc_macro_attribute]
+pub fn delegate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = Args {
+        both: false,
+        krate: parse_quote!(::anylr),
+    };
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("both") {
+            args.both = true;
+            Result::Ok(())
+        } else if meta.path.is_ident("crate") {
+            args.krate = meta.value()?.parse()?;
+            Result::Ok(())
+        } else {
+            Result::Err(meta.error("expected `both` or `crate = path`"))
+        }
+    });
+    parse_macro_input!(attr with parser);
+    let item = parse_macro_input!(item as ItemTrait);
+
+    let expanded = match expand(&args, &item) {
+        Result::Ok(impls) => impls,
+        Result::Err(e) => e.to_compile_error(),
+    };
+    quote!(#item #expanded).into()
+}
+
+struct Args {
+    both: bool,
+    krate: Path,
+}
+
+enum Target {
+    Either,
+    Both,
+}
+
+fn expand(args: &Args, item: &ItemTrait) -> syn::Result<TokenStream2> {
+    let mut errors = Vec::new();
+    let mut assoc_types = Vec::new();
+    let mut methods = Vec::new();
+    for trait_item in &item.items {
+        match trait_item {
+            TraitItem::Type(ty) if ty.generics.params.is_empty() => {
+                assoc_types.push(&ty.ident)
+            }
+            TraitItem::Type(ty) => errors.push(syn::Error::new_spanned(
+                &ty.generics,
+                "generic associated types cannot be delegated",
+            )),
+            TraitItem::Fn(f) => methods.push(f),
+            TraitItem::Const(c) => errors.push(syn::Error::new_spanned(
+                &c.ident,
+                "associated constants cannot be delegated",
+            )),
+            other => errors.push(syn::Error::new_spanned(
+                other,
+                "this trait item cannot be delegated",
+            )),
+        }
+    }
+
+    let mut impls = TokenStream2::new();
+    let mut targets = vec![Target::Either];
+    if args.both {
+        targets.push(Target::Both);
+    }
+    for target in &targets {
+        let mut fns = Vec::new();
+        for f in &methods {
+            match delegate_fn(args, item, target, f) {
+                Result::Ok(f) => fns.push(f),
+                Result::Err(e) => errors.push(e),
+            }
+        }
+        impls.extend(delegate_impl(args, item, target, &assoc_types, &fns));
+    }
+
+    match errors.into_iter().reduce(|mut all, e| {
+        all.combine(e);
+        all
+    }) {
+        Option::Some(e) => Result::Err(e),
+        Option::None => Result::Ok(impls),
+    }
+}
+
+fn delegate_impl(
+    args: &Args,
+    item: &ItemTrait,
+    target: &Target,
+    assoc_types: &[&Ident],
+    fns: &[TokenStream2],
+) -> TokenStream2 {
+    let krate = &args.krate;
+    let trait_ident = &item.ident;
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let trait_path = quote!(#trait_ident #ty_generics);
+
+    let mut trait_args: Vec<TokenStream2> = item
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(l) => {
+                let l = &l.lifetime;
+                quote!(#l)
+            }
+            GenericParam::Type(t) => {
+                let t = &t.ident;
+                quote!(#t)
+            }
+            GenericParam::Const(c) => {
+                let c = &c.ident;
+                quote!(#c)
+            }
+        })
+        .collect();
+    trait_args.extend(
+        assoc_types
+            .iter()
+            .map(|a| quote!(#a = <__L as #trait_path>::#a)),
+    );
+
+    let mut generics = item.generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(t) => {
+                t.eq_token = Option::None;
+                t.default = Option::None;
+            }
+            GenericParam::Const(c) => {
+                c.eq_token = Option::None;
+                c.default = Option::None;
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    generics.params.push(parse_quote!(__L: #trait_path));
+    generics
+        .params
+        .push(parse_quote!(__R: #trait_ident<#(#trait_args),*>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let self_ty = match target {
+        Target::Either => quote!(#krate::Either<__L, __R>),
+        Target::Both => quote!((__L, __R)),
+    };
+    let unsafety = &item.unsafety;
+    quote! {
+        #[automatically_derived]
+        #unsafety impl #impl_generics #trait_path for #self_ty #where_clause {
+            #(type #assoc_types = <__L as #trait_path>::#assoc_types;)*
+            #(#fns)*
+        }
+    }
+}
+
+fn delegate_fn(
+    args: &Args,
+    item: &ItemTrait,
+    target: &Target,
+    f: &TraitItemFn,
+) -> syn::Result<TokenStream2> {
+    let krate = &args.krate;
+    let trait_ident = &item.ident;
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let trait_path = quote!(#trait_ident #ty_generics);
+
+    let mut sig = f.sig.clone();
+    let name = &sig.ident;
+    match sig.receiver() {
+        Option::Some(r) if r.colon_token.is_none() => {}
+        Option::Some(r) => {
+            return Result::Err(syn::Error::new_spanned(
+                r,
+                "only `self`, `&self` and `&mut self` receivers can be delegated",
+            ))
+        }
+        Option::None => {
+            return Result::Err(syn::Error::new_spanned(
+                &sig,
+                "methods without a receiver cannot be delegated",
+            ))
+        }
+    }
+
+    let mut arg_names = Vec::new();
+    let mut has_impl_arg = false;
+    for (i, input) in sig.inputs.iter_mut().enumerate() {
+        if let FnArg::Typed(pt) = input {
+            if mentions_self(&pt.ty) {
+                return Result::Err(syn::Error::new_spanned(
+                    &pt.ty,
+                    "arguments of type `Self` cannot be delegated",
+                ));
+            }
+            has_impl_arg |= mentions_impl(&pt.ty);
+            let arg = format_ident!("__arg{}", i);
+            *pt.pat = Pat::Verbatim(quote!(#arg));
+            arg_names.push(arg);
+        }
+    }
+
+    let returns_self = match &sig.output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) if is_bare_self(ty) => true,
+        ReturnType::Type(_, ty) if mentions_self(ty) => {
+            return Result::Err(syn::Error::new_spanned(
+                ty,
+                "only `Self` itself can be returned from a delegated method",
+            ))
+        }
+        ReturnType::Type(..) => false,
+    };
+
+    let turbofish = {
+        let params: Vec<_> = sig
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(t) => Option::Some(&t.ident),
+                GenericParam::Const(c) => Option::Some(&c.ident),
+                GenericParam::Lifetime(_) => Option::None,
+            })
+            .collect();
+        if params.is_empty() || has_impl_arg {
+            quote!()
+        } else {
+            quote!(::<#(#params),*>)
+        }
+    };
+    let call = |side: TokenStream2, value: TokenStream2, args: &[TokenStream2]| {
+        let mut call = quote!(<#side as #trait_path>::#name #turbofish(#value, #(#args),*));
+        if sig.asyncness.is_some() {
+            call = quote!(#call.await);
+        }
+        if sig.unsafety.is_some() {
+            call = quote!(unsafe { #call });
+        }
+        call
+    };
+
+    let body = match target {
+        Target::Either => {
+            let moved: Vec<_> = arg_names.iter().map(|a| quote!(#a)).collect();
+            let left = call(quote!(__L), quote!(__x), &moved);
+            let right = call(quote!(__R), quote!(__x), &moved);
+            let (left, right) = if returns_self {
+                (
+                    quote!(#krate::Either::Left(#left)),
+                    quote!(#krate::Either::Right(#right)),
+                )
+            } else {
+                (left, right)
+            };
+            quote! {
+                match self {
+                    #krate::Either::Left(__x) => #left,
+                    #krate::Either::Right(__x) => #right,
+                }
+            }
+        }
+        Target::Both => {
+            if !matches!(sig.output, ReturnType::Default) {
+                return Result::Err(syn::Error::new_spanned(
+                    &sig.output,
+                    "methods broadcast to both values must return `()`",
+                ));
+            }
+            let cloned: Vec<_> = arg_names
+                .iter()
+                .map(|a| quote!(::core::clone::Clone::clone(&#a)))
+                .collect();
+            let moved: Vec<_> = arg_names.iter().map(|a| quote!(#a)).collect();
+            let left = call(quote!(__L), quote!(__l), &cloned);
+            let right = call(quote!(__R), quote!(__r), &moved);
+            quote! {
+                let (__l, __r,) = self;
+                #left;
+                #right;
+            }
+        }
+    };
+
+    let cfgs = f.attrs.iter().filter(|a| a.path().is_ident("cfg"));
+    Result::Ok(quote! {
+        #(#cfgs)*
+        #[inline]
+        #sig {
+            #body
+        }
+    })
+}
+
+struct SelfFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for SelfFinder {
+    fn visit_type_path(&mut self, ty: &'ast TypePath) {
+        match &ty.qself {
+            // `<Self as Tr>::Out` names an associated type, just as `Self::Out`
+            // does, so only the trait path is searched.
+            Option::Some(qself) if is_bare_self(&qself.ty) => self.visit_path(&ty.path),
+            _ => {
+                if ty.qself.is_none() && ty.path.is_ident("Self") {
+                    self.found = true;
+                }
+                visit::visit_type_path(self, ty);
+            }
+        }
+    }
+}
+
+/// Whether `Self` is used as a type, rather than as the prefix of an
+/// associated type such as `Self::Out` or `<Self as Tr>::Out`.
+fn mentions_self(ty: &Type) -> bool {
+    let mut finder = SelfFinder { found: false };
+    finder.visit_type(ty);
+    finder.found
+}
+
+fn is_bare_self(ty: &Type) -> bool {
+    matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self"))
+}
+
+struct ImplFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for ImplFinder {
+    fn visit_type_impl_trait(&mut self, _: &'ast syn::TypeImplTrait) {
+        self.found = true;
+    }
+}
+
+fn mentions_impl(ty: &Type) -> bool {
+    let mut finder = ImplFinder { found: false };
+    finder.visit_type(ty);
+    finder.found
+}
@@ -1,6 +1,13 @@
-use core::convert::Infallible;
+use core::{
+    convert::Infallible,
+    ops::ControlFlow,
+    task::Poll,
+};
 
-use crate::{Any, SomeOf};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::{Any, Shape, SomeOf};
 
 /// A trait for types that you can reverse left to right, or the opposite.
 pub trait TrReverseLeftRight {
@@ -59,12 +66,18 @@ pub trait TrAnyLeftRight {
         self.split().1
     }
 
+    /// Tells which sides are present, without consuming or moving the values.
+    fn shape(&self) -> Shape {
+        let (l, r) = self.as_ref().split();
+        Shape::new(l.is_some(), r.is_some())
+    }
+
     fn contains_left(&self) -> bool {
-        self.as_ref().pick_left().is_some()
+        self.shape().has_left()
     }
 
     fn contains_right(&self) -> bool {
-        self.as_ref().pick_right().is_some()
+        self.shape().has_right()
     }
 
     fn contains_left_and<F>(&self, f: F) -> bool
@@ -88,6 +101,254 @@ pub trait TrAnyLeftRight {
             false
         }
     }
+
+    /// Calls `f` with a reference to the left value if present, and returns
+    /// `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::abs::TrAnyLeftRight;
+    ///
+    /// let mut oks = 0;
+    /// let r: Result<u8, ()> = TrAnyLeftRight::inspect_left(Ok(1), |_| oks += 1);
+    /// let p = (1u8, 'a').inspect_both(|_, _| oks += 1);
+    /// assert_eq!((r, p, oks), (Ok(1), (1, 'a'), 2));
+    /// ```
+    fn inspect_left<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Lt),
+    {
+        if let Option::Some(l) = self.as_ref().pick_left() {
+            f(l)
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the right value if present, and returns
+    /// `self` untouched.
+    fn inspect_right<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Rt),
+    {
+        if let Option::Some(r) = self.as_ref().pick_right() {
+            f(r)
+        }
+        self
+    }
+
+    /// Calls `f` with references to both values if both are present, and
+    /// returns `self` untouched.
+    fn inspect_both<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Lt, &Self::Rt),
+    {
+        if let (Option::Some(l), Option::Some(r)) = self.as_ref().split() {
+            f(l, r)
+        }
+        self
+    }
+}
+
+/// A dyn-compatible view of `TrAnyLeftRight`, so that different left-right
+/// containers can be used through `&dyn` or `Box<dyn>`.
+///
+/// It is implemented for every `TrAnyLeftRight` type. Its methods have names
+/// of their own, so both traits can be imported together.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::{abs::TrDynAnyLeftRight, Any, Either, Shape};
+///
+/// let mut items: [&mut dyn TrDynAnyLeftRight<u8, char>; 3] = [
+///     &mut Result::<u8, char>::Ok(1),
+///     &mut Either::<u8, char>::Right('x'),
+///     &mut Any::<u8, char>::new_neither(),
+/// ];
+/// for item in items.iter_mut() {
+///     if let Some(l) = item.left_mut() {
+///         *l += 1;
+///     }
+/// }
+/// assert_eq!(items[0].left_ref(), Some(&2));
+/// assert_eq!(items[1].right_ref(), Some(&'x'));
+/// assert_eq!(items[2].dyn_shape(), Shape::Neither);
+/// ```
+pub trait TrDynAnyLeftRight<L, R> {
+    fn left_ref(&self) -> Option<&L>;
+
+    fn right_ref(&self) -> Option<&R>;
+
+    fn left_mut(&mut self) -> Option<&mut L>;
+
+    fn right_mut(&mut self) -> Option<&mut R>;
+
+    /// The shape of the value, as `TrAnyLeftRight::shape`.
+    ///
+    /// ```
+    /// use anylr::{abs::{TrAnyLeftRight, TrDynAnyLeftRight}, Either, Shape};
+    ///
+    /// let e = Either::<u8, char>::Left(1);
+    /// assert_eq!(e.shape(), Shape::Left);
+    /// assert_eq!(e.dyn_shape(), Shape::Left);
+    /// ```
+    fn dyn_shape(&self) -> Shape;
+
+    /// Splits a boxed value into its left and right values.
+    ///
+    /// ```
+    /// use anylr::{abs::TrDynAnyLeftRight, Any};
+    ///
+    /// let b: Box<dyn TrDynAnyLeftRight<u8, char>> = Box::new(Any::new_both(1, 'a'));
+    /// assert_eq!(b.split_boxed(), (Some(1), Some('a')));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn split_boxed(self: Box<Self>) -> (Option<L>, Option<R>);
+
+    /// Moves the left value out if there is one, or gives the container back.
+    ///
+    /// ```
+    /// use anylr::{abs::TrDynAnyLeftRight, Any, Shape};
+    ///
+    /// let b: Box<dyn TrDynAnyLeftRight<u8, char>> = Box::new(Any::new_both(1, 'a'));
+    /// let (l, rest) = b.take_left_boxed().split();
+    /// assert_eq!(l, Some(1));
+    /// assert_eq!(rest.unwrap().right_ref(), Some(&'a'));
+    ///
+    /// let b: Box<dyn TrDynAnyLeftRight<u8, char>> = Box::new(Err::<u8, char>('a'));
+    /// let rest = b.take_left_boxed().unwrap_right();
+    /// assert_eq!(rest.dyn_shape(), Shape::Right);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn take_left_boxed<'a>(self: Box<Self>) -> SomeOf<L, Box<dyn TrDynAnyLeftRight<L, R> + 'a>>
+    where
+        Self: 'a;
+
+    /// Moves the right value out if there is one, or gives the container back.
+    ///
+    /// ```
+    /// use anylr::{abs::TrDynAnyLeftRight, Either, Shape};
+    ///
+    /// let b: Box<dyn TrDynAnyLeftRight<u8, char>> = Box::new(Either::<u8, char>::Right('a'));
+    /// assert_eq!(b.take_right_boxed().unwrap_left(), 'a');
+    ///
+    /// let b: Box<dyn TrDynAnyLeftRight<u8, char>> = Box::new(Either::<u8, char>::Left(1));
+    /// let rest = b.take_right_boxed().unwrap_right();
+    /// assert_eq!(rest.left_ref(), Some(&1));
+    /// assert_eq!(rest.dyn_shape(), Shape::Left);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn take_right_boxed<'a>(self: Box<Self>) -> SomeOf<R, Box<dyn TrDynAnyLeftRight<L, R> + 'a>>
+    where
+        Self: 'a;
+}
+
+impl<T: TrAnyLeftRight> TrDynAnyLeftRight<T::Lt, T::Rt> for T {
+    fn left_ref(&self) -> Option<&T::Lt> {
+        TrAnyLeftRight::as_ref(self).split().0
+    }
+
+    fn right_ref(&self) -> Option<&T::Rt> {
+        TrAnyLeftRight::as_ref(self).split().1
+    }
+
+    fn left_mut(&mut self) -> Option<&mut T::Lt> {
+        TrAnyLeftRight::as_mut(self).split().0
+    }
+
+    fn right_mut(&mut self) -> Option<&mut T::Rt> {
+        TrAnyLeftRight::as_mut(self).split().1
+    }
+
+    #[inline]
+    fn dyn_shape(&self) -> Shape {
+        TrAnyLeftRight::shape(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn split_boxed(self: Box<Self>) -> (Option<T::Lt>, Option<T::Rt>) {
+        TrAnyLeftRight::split(*self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn take_left_boxed<'a>(self: Box<Self>) -> SomeOf<T::Lt, Box<dyn TrDynAnyLeftRight<T::Lt, T::Rt> + 'a>>
+    where
+        Self: 'a,
+    {
+        TrAnyLeftRight::take_left(*self)
+            .map_right(|rest| Box::new(rest) as Box<dyn TrDynAnyLeftRight<T::Lt, T::Rt> + 'a>)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn take_right_boxed<'a>(self: Box<Self>) -> SomeOf<T::Rt, Box<dyn TrDynAnyLeftRight<T::Lt, T::Rt> + 'a>>
+    where
+        Self: 'a,
+    {
+        TrAnyLeftRight::take_right(*self)
+            .map_right(|rest| Box::new(rest) as Box<dyn TrDynAnyLeftRight<T::Lt, T::Rt> + 'a>)
+    }
+}
+
+/// Splits an iterator of left-right values into the left values and the
+/// right values. `par::TrParallelPartition` is its parallel counterpart.
+pub trait TrPartition: Iterator {
+    /// Collects every left value into `A` and every right value into `B`.
+    /// Works for items of any `TrAnyLeftRight` type, so a `SomeOf` or an
+    /// `Any` can feed both sides or neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{abs::TrPartition, Any, Either};
+    ///
+    /// let (evens, odds): (Vec<u32>, Vec<u32>) = (0..6u32)
+    ///     .map(|x| if x % 2 == 0 { Either::Left(x) } else { Either::Right(x) })
+    ///     .partition_left_right();
+    /// assert_eq!((evens, odds), (vec![0, 2, 4], vec![1, 3, 5]));
+    ///
+    /// let (small, chars): (Vec<u8>, String) = [
+    ///     Any::new_both(1u8, 'a'),
+    ///     Any::new_neither(),
+    ///     Any::new_right('b'),
+    /// ]
+    /// .into_iter()
+    /// .partition_left_right();
+    /// assert_eq!((small, chars.as_str()), (vec![1], "ab"));
+    /// ```
+    fn partition_left_right<A, B>(self) -> (A, B)
+    where
+        Self: Sized,
+        Self::Item: TrAnyLeftRight,
+        A: Default + Extend<<Self::Item as TrAnyLeftRight>::Lt>,
+        B: Default + Extend<<Self::Item as TrAnyLeftRight>::Rt>,
+    {
+        let mut a = A::default();
+        let mut b = B::default();
+        for item in self {
+            let (l, r) = item.split();
+            a.extend(l);
+            b.extend(r);
+        }
+        (a, b)
+    }
+}
+
+impl<I: Iterator> TrPartition for I {}
+
+impl<T, E> TrReverseLeftRight for Result<T, E> {
+    type Lt = T;
+    type Rt = E;
+
+    fn reverse(self) -> impl TrReverseLeftRight<Lt = Self::Rt, Rt = Self::Lt> {
+        match self {
+            Result::Ok(t) => Result::<E, T>::Err(t),
+            Result::Err(e) => Result::<E, T>::Ok(e),
+        }
+    }
 }
 
 impl<T, E> TrAnyLeftRight for Result<T, E> {
@@ -152,6 +413,11 @@ impl<T, E> TrAnyLeftRight for Result<T, E> {
     {
         self.as_mut()
     }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Shape::new(self.is_ok(), self.is_err())
+    }
 }
 
 impl<T> TrAnyLeftRight for Option<T> {
@@ -216,4 +482,356 @@ impl<T> TrAnyLeftRight for Option<T> {
             Any::new_neither()
         }
     }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Shape::new(self.is_some(), false)
+    }
+}
+
+impl<B, C> TrReverseLeftRight for ControlFlow<B, C> {
+    type Lt = C;
+    type Rt = B;
+
+    fn reverse(self) -> impl TrReverseLeftRight<Lt = Self::Rt, Rt = Self::Lt> {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::<C, B>::Break(c),
+            ControlFlow::Break(b) => ControlFlow::<C, B>::Continue(b),
+        }
+    }
+}
+
+/// `Continue` is the left side and `Break` is the right side, like `Ok` and
+/// `Err` of a `Result`.
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::ControlFlow;
+/// use anylr::{abs::TrAnyLeftRight, Shape};
+///
+/// let flow = ControlFlow::<&str, u8>::Continue(1);
+/// assert_eq!(flow.shape(), Shape::Left);
+/// assert_eq!(flow.split(), (Some(1), None));
+/// ```
+impl<B, C> TrAnyLeftRight for ControlFlow<B, C> {
+    type Lt = C;
+    type Rt = B;
+
+    fn split(self) -> (Option<Self::Lt>, Option<Self::Rt>) {
+        match self {
+            ControlFlow::Continue(c) => (Option::Some(c), Option::None),
+            ControlFlow::Break(b) => (Option::None, Option::Some(b)),
+        }
+    }
+
+    fn map_left<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = U, Rt = Self::Rt>
+    where
+        F: FnOnce(Self::Lt) -> U,
+    {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(f(c)),
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+        }
+    }
+
+    fn map_right<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = Self::Lt, Rt = U>
+    where
+        F: FnOnce(Self::Rt) -> U,
+    {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(f(b)),
+        }
+    }
+
+    fn take_left(self) -> SomeOf<C, Self> {
+        match self {
+            ControlFlow::Continue(c) => SomeOf::new_left(c),
+            ControlFlow::Break(b) => SomeOf::new_right(ControlFlow::Break(b)),
+        }
+    }
+
+    fn take_right(self) -> SomeOf<B, Self> {
+        match self {
+            ControlFlow::Continue(c) => SomeOf::new_right(ControlFlow::Continue(c)),
+            ControlFlow::Break(b) => SomeOf::new_left(b),
+        }
+    }
+
+    fn as_ref<'a>(&'a self) -> impl TrAnyLeftRight<Lt = &'a Self::Lt, Rt = &'a Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+        }
+    }
+
+    fn as_mut<'a>(&'a mut self) -> impl TrAnyLeftRight<Lt = &'a mut Self::Lt, Rt = &'a mut Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+        }
+    }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Shape::new(self.is_continue(), self.is_break())
+    }
+}
+
+impl<T, E> TrReverseLeftRight for Poll<Result<T, E>> {
+    type Lt = T;
+    type Rt = E;
+
+    fn reverse(self) -> impl TrReverseLeftRight<Lt = Self::Rt, Rt = Self::Lt> {
+        match self {
+            Poll::Ready(Result::Ok(t)) => Poll::<Result<E, T>>::Ready(Result::Err(t)),
+            Poll::Ready(Result::Err(e)) => Poll::Ready(Result::Ok(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// `Ready(Ok(_))` is the left side, `Ready(Err(_))` is the right side, and
+/// `Pending` contains neither.
+impl<T, E> TrAnyLeftRight for Poll<Result<T, E>> {
+    type Lt = T;
+    type Rt = E;
+
+    fn split(self) -> (Option<Self::Lt>, Option<Self::Rt>) {
+        match self {
+            Poll::Ready(r) => r.split(),
+            Poll::Pending => (Option::None, Option::None),
+        }
+    }
+
+    fn map_left<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = U, Rt = Self::Rt>
+    where
+        F: FnOnce(Self::Lt) -> U,
+    {
+        self.map_ok(f)
+    }
+
+    fn map_right<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = Self::Lt, Rt = U>
+    where
+        F: FnOnce(Self::Rt) -> U,
+    {
+        self.map_err(f)
+    }
+
+    fn take_left(self) -> SomeOf<T, Self> {
+        match self {
+            Poll::Ready(Result::Ok(t)) => SomeOf::new_left(t),
+            x => SomeOf::new_right(x),
+        }
+    }
+
+    fn take_right(self) -> SomeOf<E, Self> {
+        match self {
+            Poll::Ready(Result::Err(e)) => SomeOf::new_left(e),
+            x => SomeOf::new_right(x),
+        }
+    }
+
+    fn as_ref<'a>(&'a self) -> impl TrAnyLeftRight<Lt = &'a Self::Lt, Rt = &'a Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        match self {
+            Poll::Ready(r) => Poll::Ready(r.as_ref()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn as_mut<'a>(&'a mut self) -> impl TrAnyLeftRight<Lt = &'a mut Self::Lt, Rt = &'a mut Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        match self {
+            Poll::Ready(r) => Poll::Ready(r.as_mut()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        match self {
+            Poll::Ready(r) => TrAnyLeftRight::shape(r),
+            Poll::Pending => Shape::Neither,
+        }
+    }
+}
+
+impl<T> TrReverseLeftRight for [T; 2] {
+    type Lt = T;
+    type Rt = T;
+
+    #[inline]
+    fn reverse(self) -> impl TrReverseLeftRight<Lt = Self::Rt, Rt = Self::Lt> {
+        let [l, r] = self;
+        [r, l]
+    }
+}
+
+/// The first element is the left side and the second is the right side, like
+/// the pair `(T, T)`. As with the pair, `take_left` and `take_right` drop the
+/// other element, since an array cannot be left with a hole.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::abs::TrAnyLeftRight;
+///
+/// assert_eq!([1, 2].split(), (Some(1), Some(2)));
+/// assert_eq!([1, 2].take_right().unwrap_left(), 2);
+/// ```
+impl<T> TrAnyLeftRight for [T; 2] {
+    type Lt = T;
+    type Rt = T;
+
+    #[inline]
+    fn split(self) -> (Option<Self::Lt>, Option<Self::Rt>) {
+        let [l, r] = self;
+        (Option::Some(l), Option::Some(r))
+    }
+
+    #[inline]
+    fn map_left<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = U, Rt = Self::Rt>
+    where
+        F: FnOnce(Self::Lt) -> U,
+    {
+        let [l, r] = self;
+        (f(l), r)
+    }
+
+    #[inline]
+    fn map_right<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = Self::Lt, Rt = U>
+    where
+        F: FnOnce(Self::Rt) -> U,
+    {
+        let [l, r] = self;
+        (l, f(r))
+    }
+
+    fn take_left(self) -> SomeOf<T, Self> {
+        let [l, _] = self;
+        SomeOf::new_left(l)
+    }
+
+    fn take_right(self) -> SomeOf<T, Self> {
+        let [_, r] = self;
+        SomeOf::new_left(r)
+    }
+
+    #[inline]
+    fn as_ref<'a>(&'a self) -> impl TrAnyLeftRight<Lt = &'a Self::Lt, Rt = &'a Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        let [l, r] = self;
+        (l, r)
+    }
+
+    #[inline]
+    fn as_mut<'a>(&'a mut self) -> impl TrAnyLeftRight<Lt = &'a mut Self::Lt, Rt = &'a mut Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        let [l, r] = self;
+        (l, r)
+    }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Shape::Both
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<X: TrReverseLeftRight> TrReverseLeftRight for Box<X> {
+    type Lt = X::Lt;
+    type Rt = X::Rt;
+
+    #[inline]
+    fn reverse(self) -> impl TrReverseLeftRight<Lt = Self::Rt, Rt = Self::Lt> {
+        X::reverse(*self)
+    }
+}
+
+/// Forwards to the boxed value.
+///
+/// `&'a mut X` is not forwarded. It could only be a container of `&'a mut
+/// X::Lt` and `&'a mut X::Rt`, but then `as_ref` and `as_mut` would have to
+/// hand out `&&'a mut X::Lt` and `&mut &'a mut X::Lt`, references to
+/// references that the `&'a mut X` does not hold anywhere. `X::as_mut`
+/// already returns a container of exactly those mutable references, so use it
+/// instead.
+#[cfg(feature = "alloc")]
+impl<X: TrAnyLeftRight> TrAnyLeftRight for Box<X> {
+    type Lt = X::Lt;
+    type Rt = X::Rt;
+
+    #[inline]
+    fn split(self) -> (Option<Self::Lt>, Option<Self::Rt>) {
+        X::split(*self)
+    }
+
+    #[inline]
+    fn map_left<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = U, Rt = Self::Rt>
+    where
+        F: FnOnce(Self::Lt) -> U,
+    {
+        X::map_left(*self, f)
+    }
+
+    #[inline]
+    fn map_right<F, U>(self, f: F) -> impl TrAnyLeftRight<Lt = Self::Lt, Rt = U>
+    where
+        F: FnOnce(Self::Rt) -> U,
+    {
+        X::map_right(*self, f)
+    }
+
+    fn take_left(self) -> SomeOf<Self::Lt, Self> {
+        X::take_left(*self).map_right(Box::new)
+    }
+
+    fn take_right(self) -> SomeOf<Self::Rt, Self> {
+        X::take_right(*self).map_right(Box::new)
+    }
+
+    #[inline]
+    fn as_ref<'a>(&'a self) -> impl TrAnyLeftRight<Lt = &'a Self::Lt, Rt = &'a Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        X::as_ref(self)
+    }
+
+    #[inline]
+    fn as_mut<'a>(&'a mut self) -> impl TrAnyLeftRight<Lt = &'a mut Self::Lt, Rt = &'a mut Self::Rt>
+    where
+        Self::Lt: 'a,
+        Self::Rt: 'a,
+    {
+        X::as_mut(self)
+    }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        X::shape(self)
+    }
 }
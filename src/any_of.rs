@@ -1,6 +1,17 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter,
+    option,
+    mem,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    task::Poll,
+};
+
 use crate::{
     abs::{TrAnyLeftRight, TrReverseLeftRight},
-    Either, SomeOf,
+    shape, Either, Shape, SomeOf,
 };
 
 /// A combination of zero or one, `L` and `R`
@@ -126,11 +137,210 @@ impl<L, R> Any<L, R> {
         }
     }
 
+    /// Calls `f` with a reference to the left value if present, and returns
+    /// `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::Cell;
+    /// use anylr::Any;
+    ///
+    /// let (lefts, boths) = (Cell::new(0), Cell::new(0));
+    /// for a in [Any::new_left(1u8), Any::new_both(2, 'b'), Any::new_right('c')] {
+    ///     let a = a
+    ///         .inspect_left(|_| lefts.set(lefts.get() + 1))
+    ///         .inspect_both(|_, _| boths.set(boths.get() + 1))
+    ///         .map_left(|l| l * 2);
+    ///     assert!(!a.is_neither());
+    /// }
+    /// assert_eq!((lefts.get(), boths.get()), (2, 1));
+    /// ```
+    pub fn inspect_left<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&L),
+    {
+        if let Option::Some(l) = self.as_ref().split().0 {
+            f(l)
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the right value if present, and returns
+    /// `self` untouched.
+    pub fn inspect_right<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&R),
+    {
+        if let Option::Some(r) = self.as_ref().split().1 {
+            f(r)
+        }
+        self
+    }
+
+    /// Calls `f` with references to both values if both are present, and
+    /// returns `self` untouched.
+    pub fn inspect_both<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&L, &R),
+    {
+        if let (Option::Some(l), Option::Some(r)) = self.as_ref().split() {
+            f(l, r)
+        }
+        self
+    }
+
+    /// Calls `f` on the left value and `g` on the right value, whichever are
+    /// present. The second call is made even if the first one fails, and the
+    /// failures are collected into a `SomeOf` that tells which sides failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let mut a = Any::<u8, u8>::new_both(1, 200);
+    /// let r = a.try_each_mut(|l| l.checked_add(100).map(|x| *l = x).ok_or("left"), |r| {
+    ///     r.checked_add(100).map(|x| *r = x).ok_or("right")
+    /// });
+    /// assert_eq!(r.unwrap_err().unwrap_right(), "right");
+    /// assert_eq!(a.split(), (Some(101), Some(200)));
+    /// ```
+    pub fn try_each_mut<F, G, E1, E2>(&mut self, f: F, g: G) -> Result<(), SomeOf<E1, E2>>
+    where
+        F: FnOnce(&mut L) -> Result<(), E1>,
+        G: FnOnce(&mut R) -> Result<(), E2>,
+    {
+        let (l, r) = self.as_mut().split();
+        let l = l.map(f).and_then(Result::err);
+        let r = r.map(g).and_then(Result::err);
+        match SomeOf::try_from((l, r,)) {
+            Result::Ok(errors) => Result::Err(errors),
+            Result::Err(_) => Result::Ok(()),
+        }
+    }
+
+    /// Removes the left value unless it satisfies `pred`, leaving the right
+    /// value untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// let a = Any::new_both(1u8, 'a');
+    /// assert_eq!(a.filter_left(|l| *l > 1).shape(), Shape::Right);
+    /// assert_eq!(a.filter_right(char::is_ascii).shape(), Shape::Both);
+    /// let a = a.filter_map_left(|l| l.checked_sub(2));
+    /// assert_eq!(a.split(), (None, Some('a')));
+    /// ```
+    pub fn filter_left<P>(self, pred: P) -> Any<L, R>
+    where
+        P: FnOnce(&L) -> bool,
+    {
+        let (l, r) = self.split();
+        Any::from((l.filter(pred), r))
+    }
+
+    /// Removes the right value unless it satisfies `pred`, leaving the left
+    /// value untouched.
+    pub fn filter_right<P>(self, pred: P) -> Any<L, R>
+    where
+        P: FnOnce(&R) -> bool,
+    {
+        let (l, r) = self.split();
+        Any::from((l, r.filter(pred)))
+    }
+
+    /// Maps the left value with `f`, removing it if `f` returns `None`.
+    pub fn filter_map_left<F, U>(self, f: F) -> Any<U, R>
+    where
+        F: FnOnce(L) -> Option<U>,
+    {
+        let (l, r) = self.split();
+        Any::from((l.and_then(f), r))
+    }
+
+    /// Maps the right value with `f`, removing it if `f` returns `None`.
+    pub fn filter_map_right<F, U>(self, f: F) -> Any<L, U>
+    where
+        F: FnOnce(R) -> Option<U>,
+    {
+        let (l, r) = self.split();
+        Any::from((l, r.and_then(f)))
+    }
+
+    /// Pairs up the values of the same side, keeping only the sides present
+    /// in both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// let a = Any::new_both(1u8, 'a');
+    /// let b = Any::<&str, f32>::new_left("one");
+    /// let z = a.zip(b);
+    /// assert_eq!(z.shape(), Shape::Left);
+    /// let (a, b) = z.unzip();
+    /// assert_eq!((a.split(), b.split()), ((Some(1), None), (Some("one"), None)));
+    /// ```
+    pub fn zip<L2, R2>(self, other: Any<L2, R2>) -> Any<(L, L2), (R, R2)> {
+        let (l1, r1) = self.split();
+        let (l2, r2) = other.split();
+        Any::from((l1.zip(l2), r1.zip(r2)))
+    }
+
+    /// Combines the values of the same side with `fl` and `fr`, keeping only
+    /// the sides present in both `self` and `other`.
+    pub fn zip_with<L2, R2, FL, FR, U, V>(
+        self,
+        other: Any<L2, R2>,
+        fl: FL,
+        fr: FR,
+    ) -> Any<U, V>
+    where
+        FL: FnOnce(L, L2) -> U,
+        FR: FnOnce(R, R2) -> V,
+    {
+        self.zip(other)
+            .map_left(|(l1, l2)| fl(l1, l2))
+            .map_right(|(r1, r2)| fr(r1, r2))
+    }
+
+    /// Converts from `&Any<L, R>` to `Any<&L::Target, &R::Target>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let mut a = Any::new_both(String::from("hi"), vec![1u8, 2]);
+    /// a.as_deref_mut().map_left(|s| s.make_ascii_uppercase());
+    /// assert_eq!(a.as_deref().split(), (Some("HI"), Some(&[1u8, 2][..])));
+    /// ```
+    pub fn as_deref(&self) -> Any<&L::Target, &R::Target>
+    where
+        L: Deref,
+        R: Deref,
+    {
+        self.as_ref().map_left(Deref::deref).map_right(Deref::deref)
+    }
+
+    /// Converts from `&mut Any<L, R>` to `Any<&mut L::Target, &mut R::Target>`.
+    pub fn as_deref_mut(&mut self) -> Any<&mut L::Target, &mut R::Target>
+    where
+        L: DerefMut,
+        R: DerefMut,
+    {
+        self.as_mut().map_left(DerefMut::deref_mut).map_right(DerefMut::deref_mut)
+    }
+
     pub fn reverse(self) -> Any<R, L> {
         Any(self.0.reverse())
     }
 
-    pub fn as_ref(&self) -> Any<&L, &R> {
+    pub const fn as_ref(&self) -> Any<&L, &R> {
         match &self.0 {
             AnyLR::Neither => Any::new_neither(),
             AnyLR::Left(l) => Any::new_left(l),
@@ -139,7 +349,7 @@ impl<L, R> Any<L, R> {
         }
     }
 
-    pub fn as_mut(&mut self) -> Any<&mut L, &mut R> {
+    pub const fn as_mut(&mut self) -> Any<&mut L, &mut R> {
         match &mut self.0 {
             AnyLR::Neither => Any::new_neither(),
             AnyLR::Left(l) => Any::new_left(l),
@@ -148,33 +358,803 @@ impl<L, R> Any<L, R> {
         }
     }
 
-    pub fn contains_left(&self) -> bool {
-        match self.0 {
-            AnyLR::Left(_) => true,
-            AnyLR::Both(_) => true,
-            _ => false,
+    /// Projects a pinned reference to pinned references of the contained
+    /// values.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Any<Pin<&L>, Pin<&R>> {
+        // SAFETY: See `as_pin_mut`.
+        unsafe {
+            match &self.get_ref().0 {
+                AnyLR::Neither => Any::new_neither(),
+                AnyLR::Left(l) => Any::new_left(Pin::new_unchecked(l)),
+                AnyLR::Right(r) => Any::new_right(Pin::new_unchecked(r)),
+                AnyLR::Both((l, r,)) => Any::new_both(Pin::new_unchecked(l), Pin::new_unchecked(r)),
+            }
         }
     }
 
-    pub fn contains_right(&self) -> bool {
-        match self.0 {
-            AnyLR::Right(_) => true,
-            AnyLR::Both(_) => true,
-            _ => false,
+    /// Projects a pinned mutable reference to pinned mutable references of
+    /// the contained values.
+    ///
+    /// The values are structurally pinned, see `Either::as_pin_mut` for why
+    /// this is sound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{cell::Cell, marker::PhantomPinned, pin::pin};
+    /// use anylr::Any;
+    ///
+    /// struct Guard<'a>(&'a Cell<u32>, PhantomPinned);
+    ///
+    /// impl Drop for Guard<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Cell::new(0);
+    /// {
+    ///     let mut a = pin!(Any::new_both(Guard(&drops, PhantomPinned), 1u8));
+    ///     let (l, r) = a.as_mut().as_pin_mut().split();
+    ///     assert!(l.is_some());
+    ///     *r.unwrap() += 1;
+    ///     assert_eq!(a.as_ref().as_pin_ref().split().1.as_deref(), Some(&2));
+    /// }
+    /// assert_eq!(drops.get(), 1);
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Any<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: The contained values are structurally pinned.
+        unsafe {
+            match &mut self.get_unchecked_mut().0 {
+                AnyLR::Neither => Any::new_neither(),
+                AnyLR::Left(l) => Any::new_left(Pin::new_unchecked(l)),
+                AnyLR::Right(r) => Any::new_right(Pin::new_unchecked(r)),
+                AnyLR::Both((l, r,)) => Any::new_both(Pin::new_unchecked(l), Pin::new_unchecked(r)),
+            }
         }
     }
 
-    pub fn is_both(&self) -> bool {
+    pub const fn contains_left(&self) -> bool {
+        matches!(self.0, AnyLR::Left(_) | AnyLR::Both(_))
+    }
+
+    pub const fn contains_right(&self) -> bool {
+        matches!(self.0, AnyLR::Right(_) | AnyLR::Both(_))
+    }
+
+    pub const fn is_both(&self) -> bool {
         matches!(self.0, AnyLR::Both(_))
     }
 
-    pub fn is_neither(&self) -> bool {
+    pub const fn is_neither(&self) -> bool {
         matches!(self.0, AnyLR::Neither)
     }
 
+    /// Tells which sides are present, without consuming or moving the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// let a = Any::<usize, f64>::new_left(0usize);
+    /// assert_eq!(a.shape(), Shape::Left);
+    /// assert!(a.shape().has_left());
+    /// ```
+    pub const fn shape(&self) -> Shape {
+        self.0.shape()
+    }
+
     pub fn into_inner(self) -> AnyLR<L, R> {
         self.0
     }
+
+    /// Returns both values if both are present, and `None` otherwise.
+    pub fn into_both(self) -> Option<(L, R)> {
+        match self.0 {
+            AnyLR::Both(pair) => Option::Some(pair),
+            _ => Option::None,
+        }
+    }
+
+    /// Returns the value as a `SomeOf<L, R>`, or `None` if it is `Neither`.
+    ///
+    /// This is one side of the isomorphism between `Any<L, R>` and
+    /// `Option<SomeOf<L, R>>`, whose other side is `Any::from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, SomeOf};
+    ///
+    /// let a = Any::new_both(1u8, 'a');
+    /// assert_eq!(a.into_both(), Some((1, 'a')));
+    /// assert!(a.into_exactly_one().is_none());
+    ///
+    /// let s: Option<SomeOf<u8, char>> = a.into_some_of();
+    /// assert_eq!(Any::from(s).split(), a.split());
+    /// assert!(Option::<SomeOf<u8, char>>::from(Any::new_neither()).is_none());
+    /// ```
+    pub fn into_some_of(self) -> Option<SomeOf<L, R>> {
+        SomeOf::try_from(self).ok()
+    }
+
+    /// Returns the value as an `Either<L, R>` if exactly one side is present,
+    /// and `None` otherwise.
+    pub fn into_exactly_one(self) -> Option<Either<L, R>> {
+        Either::try_from(self).ok()
+    }
+
+    /// Takes the value out, leaving `Neither` in its place.
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
+    /// Replaces the value with `value`, returning the old one.
+    #[inline]
+    pub fn replace(&mut self, value: Self) -> Self {
+        mem::replace(self, value)
+    }
+
+    /// Sets the left value, returning the previous one if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// let mut a = Any::<u8, char>::new_right('a');
+    /// assert_eq!(a.insert_left(1), None);
+    /// assert_eq!(a.insert_left(2), Some(1));
+    /// assert_eq!(a.shape(), Shape::Both);
+    /// ```
+    pub fn insert_left(&mut self, l: L) -> Option<L> {
+        let (prev, r) = self.take().split();
+        *self = Any::from((Option::Some(l), r));
+        prev
+    }
+
+    /// Sets the right value, returning the previous one if any.
+    pub fn insert_right(&mut self, r: R) -> Option<R> {
+        let (l, prev) = self.take().split();
+        *self = Any::from((l, Option::Some(r)));
+        prev
+    }
+
+    /// Removes the left value in place, leaving the right value untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// let mut a = Any::new_both(1u8, 'a');
+    /// assert_eq!(a.take_left_in_place(), Some(1));
+    /// assert_eq!(a.take_left_in_place(), None);
+    /// assert_eq!(a.shape(), Shape::Right);
+    /// ```
+    pub fn take_left_in_place(&mut self) -> Option<L> {
+        let (l, r) = self.take().split();
+        *self = Any::from((Option::None, r));
+        l
+    }
+
+    /// Removes the right value in place, leaving the left value untouched.
+    pub fn take_right_in_place(&mut self) -> Option<R> {
+        let (l, r) = self.take().split();
+        *self = Any::from((l, Option::None));
+        r
+    }
+
+    /// Returns the left value, inserting the one computed by `f` if absent.
+    pub fn get_or_insert_left_with<F>(&mut self, f: F) -> &mut L
+    where
+        F: FnOnce() -> L,
+    {
+        if !self.contains_left() {
+            self.insert_left(f());
+        }
+        match &mut self.0 {
+            AnyLR::Left(l) | AnyLR::Both((l, _,)) => l,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the right value, inserting the one computed by `f` if absent.
+    pub fn get_or_insert_right_with<F>(&mut self, f: F) -> &mut R
+    where
+        F: FnOnce() -> R,
+    {
+        if !self.contains_right() {
+            self.insert_right(f());
+        }
+        match &mut self.0 {
+            AnyLR::Right(r) | AnyLR::Both((_, r,)) => r,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Gets the left slot for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let mut a = Any::<u32, char>::new_neither();
+    /// *a.left_entry().or_insert(1) += 1;
+    /// a.left_entry().and_modify(|l| *l *= 10).or_insert(0);
+    /// assert_eq!(a.split(), (Some(20), None));
+    /// ```
+    pub fn left_entry(&mut self) -> LeftEntry<'_, L, R> {
+        LeftEntry(self)
+    }
+
+    /// Gets the right slot for in-place manipulation.
+    pub fn right_entry(&mut self) -> RightEntry<'_, L, R> {
+        RightEntry(self)
+    }
+
+    /// Returns the left value, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the left value is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// assert_eq!(Any::<u8, char>::new_both(1, 'a').unwrap_left(), 1);
+    ///
+    /// // Use the defaulting accessors when a side may be absent.
+    /// let a = Any::<u8, char>::new_right('a');
+    /// assert_eq!(a.right_or('b'), 'a');
+    /// assert_eq!(a.left_or_default(), 0);
+    /// assert_eq!(a.both_or_else(|| (1, 'b')), (1, 'b'));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use anylr::Any;
+    ///
+    /// // panics with "called `Any::unwrap_left()` on a value of shape `Right`"
+    /// Any::<u8, char>::new_right('a').unwrap_left();
+    /// ```
+    #[track_caller]
+    pub fn unwrap_left(self) -> L {
+        let found = self.shape();
+        match self.split().0 {
+            Option::Some(l) => l,
+            Option::None => shape::unwrap_failed("Any::unwrap_left()", found),
+        }
+    }
+
+    /// Returns the right value, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the right value is absent.
+    #[track_caller]
+    pub fn unwrap_right(self) -> R {
+        let found = self.shape();
+        match self.split().1 {
+            Option::Some(r) => r,
+            Option::None => shape::unwrap_failed("Any::unwrap_right()", found),
+        }
+    }
+
+    /// Returns both values, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values is absent.
+    #[track_caller]
+    pub fn unwrap_both(self) -> (L, R) {
+        let found = self.shape();
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => shape::unwrap_failed("Any::unwrap_both()", found),
+        }
+    }
+
+    /// Returns the left value, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the left value is absent.
+    #[track_caller]
+    pub fn expect_left(self, msg: &str) -> L {
+        let found = self.shape();
+        match self.split().0 {
+            Option::Some(l) => l,
+            Option::None => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns the right value, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the right value is absent.
+    #[track_caller]
+    pub fn expect_right(self, msg: &str) -> R {
+        let found = self.shape();
+        match self.split().1 {
+            Option::Some(r) => r,
+            Option::None => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns both values, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values is absent.
+    #[track_caller]
+    pub fn expect_both(self, msg: &str) -> (L, R) {
+        let found = self.shape();
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns the left value, or `default` if absent.
+    pub fn left_or(self, default: L) -> L {
+        self.split().0.unwrap_or(default)
+    }
+
+    /// Returns the left value, or computes one with `f` if absent.
+    pub fn left_or_else<F>(self, f: F) -> L
+    where
+        F: FnOnce() -> L,
+    {
+        self.split().0.unwrap_or_else(f)
+    }
+
+    /// Returns the left value, or `L::default()` if absent.
+    pub fn left_or_default(self) -> L
+    where
+        L: Default,
+    {
+        self.split().0.unwrap_or_default()
+    }
+
+    /// Returns the right value, or `default` if absent.
+    pub fn right_or(self, default: R) -> R {
+        self.split().1.unwrap_or(default)
+    }
+
+    /// Returns the right value, or computes one with `f` if absent.
+    pub fn right_or_else<F>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.split().1.unwrap_or_else(f)
+    }
+
+    /// Returns the right value, or `R::default()` if absent.
+    pub fn right_or_default(self) -> R
+    where
+        R: Default,
+    {
+        self.split().1.unwrap_or_default()
+    }
+
+    /// Returns both values, or `default` unless both are present.
+    pub fn both_or(self, default: (L, R)) -> (L, R) {
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => default,
+        }
+    }
+
+    /// Returns both values, or computes a pair with `f` unless both are
+    /// present.
+    pub fn both_or_else<F>(self, f: F) -> (L, R)
+    where
+        F: FnOnce() -> (L, R),
+    {
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => f(),
+        }
+    }
+}
+
+impl<L, R> Any<&L, &R> {
+    /// Maps a `Any<&L, &R>` to a `Any<L, R>` by cloning the contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let a = Any::new_both(1u8, String::from("a"));
+    /// let b: Any<u8, String> = a.as_ref().cloned();
+    /// assert_eq!(b.split(), (Some(1), Some(String::from("a"))));
+    /// ```
+    pub fn cloned(self) -> Any<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(L::clone).map_right(R::clone)
+    }
+
+    /// Maps a `Any<&L, &R>` to a `Any<L, R>` by copying the contents.
+    pub fn copied(self) -> Any<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<L, R> Any<&mut L, &mut R> {
+    /// Maps a `Any<&mut L, &mut R>` to a `Any<L, R>` by cloning the contents.
+    pub fn cloned(self) -> Any<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(|l| l.clone()).map_right(|r| r.clone())
+    }
+
+    /// Maps a `Any<&mut L, &mut R>` to a `Any<L, R>` by copying the contents.
+    pub fn copied(self) -> Any<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<A, B, C, D> Any<(A, B), (C, D)> {
+    /// Splits an `Any` of pairs into a pair of `Any`s of the same shape.
+    pub fn unzip(self) -> (Any<A, C>, Any<B, D>) {
+        match self.0 {
+            AnyLR::Neither => (Any::new_neither(), Any::new_neither()),
+            AnyLR::Left((a, b)) => (Any::new_left(a), Any::new_left(b)),
+            AnyLR::Right((c, d)) => (Any::new_right(c), Any::new_right(d)),
+            AnyLR::Both(((a, b), (c, d))) => (Any::new_both(a, c), Any::new_both(b, d)),
+        }
+    }
+}
+
+impl<A, B, C> Any<(A, B), (A, C)> {
+    /// Distributes `A` over both sides of an `Any<B, C>`, cloning it if both
+    /// sides are present. Note that `A` is dropped if neither side is present,
+    /// so `factor` cannot always undo it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let a = Any::distribute((1u8, Any::<char, &str>::new_left('b')));
+    /// assert_eq!(a.split(), (Some((1, 'b')), None));
+    /// let (x, a) = a.factor().unwrap();
+    /// assert_eq!((x, a.split()), (1, (Some('b'), None)));
+    /// ```
+    pub fn distribute(pair: (A, Any<B, C>)) -> Self
+    where
+        A: Clone,
+    {
+        let (a, x) = pair;
+        match x.0 {
+            AnyLR::Neither => Any::new_neither(),
+            AnyLR::Left(b) => Any::new_left((a, b)),
+            AnyLR::Right(c) => Any::new_right((a, c)),
+            AnyLR::Both((b, c,)) => Any::new_both((a.clone(), b), (a, c)),
+        }
+    }
+
+    /// Factors `A` out of both sides, or returns `None` if neither side is
+    /// present. If both sides are present, the left `A` is kept and the right
+    /// one is dropped.
+    pub fn factor(self) -> Option<(A, Any<B, C>)> {
+        match self.0 {
+            AnyLR::Neither => Option::None,
+            AnyLR::Left((a, b)) => Option::Some((a, Any::new_left(b))),
+            AnyLR::Right((a, c)) => Option::Some((a, Any::new_right(c))),
+            AnyLR::Both(((a, b), (_, c))) => Option::Some((a, Any::new_both(b, c))),
+        }
+    }
+}
+
+impl<T> Any<T, T> {
+    /// Applies the same function to every present value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let a = Any::new_both(1, 2).map_both(|x| x * 10);
+    /// assert_eq!(a.into_iter().collect::<Vec<_>>(), [10, 20]);
+    /// ```
+    pub fn map_both<F, U>(self, mut f: F) -> Any<U, U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self.0 {
+            AnyLR::Neither => Any::new_neither(),
+            AnyLR::Left(l) => Any::new_left(f(l)),
+            AnyLR::Right(r) => Any::new_right(f(r)),
+            AnyLR::Both((l, r,)) => Any::new_both(f(l), f(r)),
+        }
+    }
+
+    /// Collapses the value into a single `T`, combining both values with `f`
+    /// if both are present, or `None` if neither is.
+    pub fn reduce<F>(self, f: F) -> Option<T>
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match self.0 {
+            AnyLR::Neither => Option::None,
+            AnyLR::Left(x) | AnyLR::Right(x) => Option::Some(x),
+            AnyLR::Both((l, r,)) => Option::Some(f(l, r)),
+        }
+    }
+
+    /// Returns the maximum of the present values with respect to `compare`,
+    /// or the right one if they are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Any;
+    ///
+    /// let a = Any::new_both("b", "a");
+    /// assert_eq!(a.max_by(|l, r| l.cmp(r)), Some("b"));
+    /// assert_eq!(a.min_by(|l, r| l.cmp(r)), Some("a"));
+    /// assert_eq!(Any::<u8, u8>::new_neither().max_by(Ord::cmp), None);
+    /// ```
+    pub fn max_by<F>(self, compare: F) -> Option<T>
+    where
+        F: FnOnce(&T, &T) -> Ordering,
+    {
+        self.reduce(|l, r| match compare(&l, &r) {
+            Ordering::Greater => l,
+            _ => r,
+        })
+    }
+
+    /// Returns the minimum of the present values with respect to `compare`,
+    /// or the left one if they are equal.
+    pub fn min_by<F>(self, compare: F) -> Option<T>
+    where
+        F: FnOnce(&T, &T) -> Ordering,
+    {
+        self.reduce(|l, r| match compare(&l, &r) {
+            Ordering::Greater => r,
+            _ => l,
+        })
+    }
+
+    /// Iterates over references to the present values, from left to right.
+    pub fn iter(&self) -> iter::Chain<option::IntoIter<&T>, option::IntoIter<&T>> {
+        self.as_ref().into_iter()
+    }
+
+    /// Iterates over mutable references to the present values, from left to
+    /// right.
+    pub fn iter_mut(&mut self) -> iter::Chain<option::IntoIter<&mut T>, option::IntoIter<&mut T>> {
+        self.as_mut().into_iter()
+    }
+
+    /// Puts the left value, if any, in the first slot and the right value, if
+    /// any, in the second slot.
+    pub fn into_array(self) -> [Option<T>; 2] {
+        let (l, r) = self.split();
+        [l, r]
+    }
+}
+
+impl<T> IntoIterator for Any<T, T> {
+    type Item = T;
+    type IntoIter = iter::Chain<option::IntoIter<T>, option::IntoIter<T>>;
+
+    /// Iterates over the present values, from left to right.
+    fn into_iter(self) -> Self::IntoIter {
+        let (l, r) = self.split();
+        l.into_iter().chain(r)
+    }
+}
+
+/// Writes every chunk to every present side. A failure on one side does not
+/// stop the other side from being written; use `try_each_mut` to find out
+/// which side failed.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use anylr::Any;
+///
+/// let mut tee = Any::new_both(String::new(), String::new());
+/// write!(tee, "{}-{}", 1, 2).unwrap();
+/// assert_eq!(tee.unwrap_both(), ("1-2".to_owned(), "1-2".to_owned()));
+/// ```
+impl<L: fmt::Write, R: fmt::Write> fmt::Write for Any<L, R> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_each_mut(|l| l.write_str(s), |r| r.write_str(s))
+            .map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_each_mut(|l| l.write_char(c), |r| r.write_char(c))
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// Extends every present side with the same items, cloning them only when
+/// both sides are present.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::Any;
+///
+/// let mut a = Any::new_both(Vec::new(), String::new());
+/// a.extend(['a', 'b']);
+/// assert_eq!(a.unwrap_both(), (vec!['a', 'b'], "ab".to_owned()));
+/// ```
+impl<T: Clone, L: Extend<T>, R: Extend<T>> Extend<T> for Any<L, R> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match &mut self.0 {
+            AnyLR::Neither => (),
+            AnyLR::Left(l) => l.extend(iter),
+            AnyLR::Right(r) => r.extend(iter),
+            AnyLR::Both((l, r,)) => {
+                for x in iter {
+                    l.extend(Option::Some(x.clone()));
+                    r.extend(Option::Some(x));
+                }
+            }
+        }
+    }
+}
+
+impl<L: Copy, R: Copy> Any<L, R> {
+    /// The same as `split`, but usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Any, Shape};
+    ///
+    /// const A: Any<u8, char> = Any::new_both(1, 'a');
+    /// const R: Any<char, u8> = A.to_reversed();
+    /// const S: (Option<char>, Option<u8>) = R.to_split();
+    /// const _: () = assert!(A.is_both() && R.contains_left());
+    /// const _: () = assert!(matches!(R.shape(), Shape::Both));
+    /// assert_eq!(S, (Some('a'), Some(1)));
+    ///
+    /// static TABLE: [Any<u8, u8>; 2] = [Any::new_neither(), Any::new_right(2)];
+    /// assert!(TABLE[0].is_neither());
+    /// assert_eq!(TABLE[1].as_ref().to_split(), (None, Some(&2)));
+    /// ```
+    pub const fn to_split(self) -> (Option<L>, Option<R>) {
+        match self.0 {
+            AnyLR::Neither => (Option::None, Option::None),
+            AnyLR::Left(l) => (Option::Some(l), Option::None),
+            AnyLR::Right(r) => (Option::None, Option::Some(r)),
+            AnyLR::Both((l, r,)) => (Option::Some(l), Option::Some(r)),
+        }
+    }
+
+    /// The same as `reverse`, but usable in const contexts.
+    pub const fn to_reversed(self) -> Any<R, L> {
+        Any(match self.0 {
+            AnyLR::Neither => AnyLR::Neither,
+            AnyLR::Left(x) => AnyLR::Right(x),
+            AnyLR::Right(x) => AnyLR::Left(x),
+            AnyLR::Both((l, r,)) => AnyLR::Both((r, l,)),
+        })
+    }
+}
+
+/// The left slot of an `Any<L, R>`, see `Any::left_entry`.
+pub struct LeftEntry<'a, L, R>(&'a mut Any<L, R>);
+
+impl<'a, L, R> LeftEntry<'a, L, R> {
+    pub fn get(&self) -> Option<&L> {
+        self.0.as_ref().split().0
+    }
+
+    pub fn or_insert(self, default: L) -> &'a mut L {
+        self.0.get_or_insert_left_with(|| default)
+    }
+
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut L
+    where
+        F: FnOnce() -> L,
+    {
+        self.0.get_or_insert_left_with(f)
+    }
+
+    pub fn or_default(self) -> &'a mut L
+    where
+        L: Default,
+    {
+        self.0.get_or_insert_left_with(L::default)
+    }
+
+    /// Applies `f` to the left value if present.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut L),
+    {
+        if let Option::Some(l) = self.0.as_mut().split().0 {
+            f(l)
+        }
+        self
+    }
+
+    /// Sets the left value, returning the previous one if any.
+    pub fn insert(self, l: L) -> Option<L> {
+        self.0.insert_left(l)
+    }
+
+    /// Removes the left value, returning it if any.
+    pub fn remove(self) -> Option<L> {
+        self.0.take_left_in_place()
+    }
+}
+
+/// The right slot of an `Any<L, R>`, see `Any::right_entry`.
+pub struct RightEntry<'a, L, R>(&'a mut Any<L, R>);
+
+impl<'a, L, R> RightEntry<'a, L, R> {
+    pub fn get(&self) -> Option<&R> {
+        self.0.as_ref().split().1
+    }
+
+    pub fn or_insert(self, default: R) -> &'a mut R {
+        self.0.get_or_insert_right_with(|| default)
+    }
+
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut R
+    where
+        F: FnOnce() -> R,
+    {
+        self.0.get_or_insert_right_with(f)
+    }
+
+    pub fn or_default(self) -> &'a mut R
+    where
+        R: Default,
+    {
+        self.0.get_or_insert_right_with(R::default)
+    }
+
+    /// Applies `f` to the right value if present.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut R),
+    {
+        if let Option::Some(r) = self.0.as_mut().split().1 {
+            f(r)
+        }
+        self
+    }
+
+    /// Sets the right value, returning the previous one if any.
+    pub fn insert(self, r: R) -> Option<R> {
+        self.0.insert_right(r)
+    }
+
+    /// Removes the right value, returning it if any.
+    pub fn remove(self) -> Option<R> {
+        self.0.take_right_in_place()
+    }
 }
 
 impl<L, R> Default for Any<L, R> {
@@ -220,6 +1200,107 @@ impl<L, R> From<(Option<L>, Option<R>,)> for Any<L, R> {
     }
 }
 
+impl<T, E> From<Result<T, E>> for Any<T, E> {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Result::Ok(t) => Any::new_left(t),
+            Result::Err(e) => Any::new_right(e),
+        }
+    }
+}
+
+/// `Pending` becomes `Neither`.
+///
+/// # Examples
+///
+/// ```
+/// use core::task::Poll;
+/// use anylr::Any;
+///
+/// assert!(Any::from(Poll::<Result<u8, ()>>::Pending).is_neither());
+/// assert_eq!(Any::from(Poll::Ready(Result::<u8, ()>::Ok(1))).split(), (Some(1), None));
+/// ```
+impl<T, E> From<Poll<Result<T, E>>> for Any<T, E> {
+    fn from(value: Poll<Result<T, E>>) -> Self {
+        match value {
+            Poll::Ready(r) => Any::from(r),
+            Poll::Pending => Any::new_neither(),
+        }
+    }
+}
+
+impl<T> From<[T; 2]> for Any<T, T> {
+    fn from(value: [T; 2]) -> Self {
+        let [l, r] = value;
+        Any::new_both(l, r)
+    }
+}
+
+impl<L, R> From<Option<L>> for Any<L, R> {
+    fn from(value: Option<L>) -> Self {
+        match value {
+            Option::Some(l) => Any::new_left(l),
+            Option::None => Any::new_neither(),
+        }
+    }
+}
+
+impl<L, R> From<Option<SomeOf<L, R>>> for Any<L, R> {
+    fn from(value: Option<SomeOf<L, R>>) -> Self {
+        match value {
+            Option::Some(s) => Any::from(s),
+            Option::None => Any::new_neither(),
+        }
+    }
+}
+
+impl<L, R> From<Any<L, R>> for Option<SomeOf<L, R>> {
+    fn from(value: Any<L, R>) -> Self {
+        value.into_some_of()
+    }
+}
+
+impl<L, R> From<Any<L, R>> for (Option<L>, Option<R>,) {
+    fn from(value: Any<L, R>) -> Self {
+        value.split()
+    }
+}
+
+/// Fails with the original value unless it is `Both`.
+impl<L, R> TryFrom<Any<L, R>> for (L, R,) {
+    type Error = Any<L, R>;
+
+    fn try_from(value: Any<L, R>) -> Result<Self, Self::Error> {
+        match value.0 {
+            AnyLR::Both(pair) => Result::Ok(pair),
+            x => Result::Err(Any(x)),
+        }
+    }
+}
+
+/// `Neither` becomes `None`. Fails with the original value if the right value
+/// is present.
+impl<L, R> TryFrom<Any<L, R>> for Option<L> {
+    type Error = Any<L, R>;
+
+    fn try_from(value: Any<L, R>) -> Result<Self, Self::Error> {
+        match value.0 {
+            AnyLR::Neither => Result::Ok(Option::None),
+            AnyLR::Left(l) => Result::Ok(Option::Some(l)),
+            x => Result::Err(Any(x)),
+        }
+    }
+}
+
+/// Fails with the original value if it is `Neither` or `Both`.
+impl<T, E> TryFrom<Any<T, E>> for Result<T, E> {
+    type Error = Any<T, E>;
+
+    fn try_from(value: Any<T, E>) -> Result<Self, Self::Error> {
+        Either::try_from(value).map(Result::from)
+    }
+}
+
 impl<L, R> TrReverseLeftRight for Any<L, R> {
     type Lt = L;
     type Rt = R;
@@ -282,6 +1363,11 @@ impl<L, R> TrAnyLeftRight for Any<L, R> {
     {
         Any::as_mut(self)
     }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Any::shape(self)
+    }
 }
 
 impl<L: Copy, R: Copy> Copy for Any<L, R>
@@ -314,14 +1400,24 @@ impl<L, R> AnyLR<L, R> {
         }
     }
 
+    pub const fn shape(&self) -> Shape {
+        match self {
+            AnyLR::Neither => Shape::Neither,
+            AnyLR::Left(_) => Shape::Left,
+            AnyLR::Right(_) => Shape::Right,
+            AnyLR::Both(_) => Shape::Both,
+        }
+    }
+
     pub(crate) fn map_left<F, U>(self, f: F) -> AnyLR<U, R>
     where
         F: FnOnce(L) -> U,
     {
         match self {
+            AnyLR::Neither => AnyLR::Neither,
             AnyLR::Left(l) => AnyLR::Left(f(l)),
+            AnyLR::Right(r) => AnyLR::Right(r),
             AnyLR::Both((l, r,)) => AnyLR::Both((f(l), r,)),
-            _ => AnyLR::Neither,
         }
     }
 
@@ -330,9 +1426,10 @@ impl<L, R> AnyLR<L, R> {
         F: FnOnce(R) -> U,
     {
         match self {
+            AnyLR::Neither => AnyLR::Neither,
+            AnyLR::Left(l) => AnyLR::Left(l),
             AnyLR::Right(r) => AnyLR::Right(f(r)),
             AnyLR::Both((l, r,)) => AnyLR::Both((l, f(r),)),
-            _ => AnyLR::Neither,
         }
     }
 }
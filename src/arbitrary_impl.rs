@@ -0,0 +1,117 @@
+use arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Unstructured};
+
+use crate::{Any, Either, SomeOf};
+
+impl<'a, L, R> Arbitrary<'a> for Any<L, R>
+where
+    L: Arbitrary<'a>,
+    R: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Result::Ok(match u.int_in_range(0u8..=3)? {
+            0 => Any::new_neither(),
+            1 => Any::new_left(L::arbitrary(u)?),
+            2 => Any::new_right(R::arbitrary(u)?),
+            _ => Any::new_both(L::arbitrary(u)?, R::arbitrary(u)?),
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+        let l = L::try_size_hint(depth)?;
+        let r = R::try_size_hint(depth)?;
+        Result::Ok(size_hint::and(
+            <u8 as Arbitrary>::size_hint(depth),
+            size_hint::or_all(&[(0, Option::Some(0)), l, r, size_hint::and(l, r)]),
+        ))
+    }
+}
+
+impl<'a, L, R> Arbitrary<'a> for SomeOf<L, R>
+where
+    L: Arbitrary<'a>,
+    R: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Result::Ok(match u.int_in_range(0u8..=2)? {
+            0 => SomeOf::new_left(L::arbitrary(u)?),
+            1 => SomeOf::new_right(R::arbitrary(u)?),
+            _ => SomeOf::new_both(L::arbitrary(u)?, R::arbitrary(u)?),
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+        let l = L::try_size_hint(depth)?;
+        let r = R::try_size_hint(depth)?;
+        Result::Ok(size_hint::and(
+            <u8 as Arbitrary>::size_hint(depth),
+            size_hint::or_all(&[l, r, size_hint::and(l, r)]),
+        ))
+    }
+}
+
+impl<'a, L, R> Arbitrary<'a> for Either<L, R>
+where
+    L: Arbitrary<'a>,
+    R: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Result::Ok(if <bool as Arbitrary<'a>>::arbitrary(u)? {
+            Either::new_left(L::arbitrary(u)?)
+        } else {
+            Either::new_right(R::arbitrary(u)?)
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+        Result::Ok(size_hint::and(
+            <bool as Arbitrary>::size_hint(depth),
+            size_hint::or(L::try_size_hint(depth)?, R::try_size_hint(depth)?),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{Any, Either, SomeOf};
+
+    /// Records the shapes generated from every possible leading byte.
+    fn shapes<T: for<'a> Arbitrary<'a>>(shape: impl Fn(&T) -> u8) -> [bool; 4] {
+        let mut seen = [false; 4];
+        for first in 0..=u8::MAX {
+            let data = [first, 1, 2, 3, 4, 5, 6, 7];
+            let x = T::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            seen[shape(&x) as usize] = true;
+        }
+        seen
+    }
+
+    #[test]
+    fn arbitrary_generates_every_shape() {
+        assert_eq!(shapes::<Any<u8, u8>>(|x| x.shape().bits()), [true; 4]);
+        assert_eq!(
+            shapes::<SomeOf<u8, u8>>(|x| x.shape().bits()),
+            [false, true, true, true],
+        );
+        assert_eq!(
+            shapes::<Either<u8, u8>>(|x| x.shape().bits()),
+            [false, true, true, false],
+        );
+    }
+}
@@ -1,8 +1,171 @@
+use core::{
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+
 use crate::{
     abs::{TrAnyLeftRight, TrReverseLeftRight},
-    SomeOf,
+    Shape, SomeOf,
 };
 
+/// Pin projections for the pair `(L, R)`.
+///
+/// Both values are structurally pinned, see `Either::as_pin_mut` for why this
+/// is sound.
+///
+/// # Examples
+///
+/// ```
+/// use core::{marker::PhantomPinned, pin::pin};
+/// use anylr::both::TrPinBoth;
+///
+/// let mut p = pin!((PhantomPinned, 1u8));
+/// let (_, r) = p.as_mut().as_pin_mut();
+/// *r.get_mut() += 1;
+/// assert_eq!(*p.as_ref().as_pin_ref().1, 2);
+/// ```
+pub trait TrPinBoth {
+    type Lt;
+    type Rt;
+
+    fn as_pin_ref(self: Pin<&Self>) -> (Pin<&Self::Lt>, Pin<&Self::Rt>);
+
+    fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut Self::Lt>, Pin<&mut Self::Rt>);
+}
+
+impl<L, R> TrPinBoth for (L, R) {
+    type Lt = L;
+    type Rt = R;
+
+    fn as_pin_ref(self: Pin<&Self>) -> (Pin<&L>, Pin<&R>) {
+        let (l, r) = self.get_ref();
+        // SAFETY: Both values are structurally pinned.
+        unsafe { (Pin::new_unchecked(l), Pin::new_unchecked(r)) }
+    }
+
+    fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        // SAFETY: Both values are structurally pinned, and neither is moved
+        // out of the pair.
+        let (l, r) = unsafe { self.get_unchecked_mut() };
+        // SAFETY: Both values are structurally pinned.
+        unsafe { (Pin::new_unchecked(l), Pin::new_unchecked(r)) }
+    }
+}
+
+/// Borrowed views of the pair `(L, R)`, like `Either::as_deref`.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::both::TrDerefBoth;
+///
+/// let p = (String::from("a"), vec![1u8]);
+/// let (s, b): (&str, &[u8]) = p.as_deref();
+/// assert_eq!((s, b), ("a", &[1u8][..]));
+/// ```
+pub trait TrDerefBoth {
+    type Lt: Deref;
+    type Rt: Deref;
+
+    fn as_deref(&self) -> (&<Self::Lt as Deref>::Target, &<Self::Rt as Deref>::Target);
+
+    fn as_deref_mut(&mut self) -> (&mut <Self::Lt as Deref>::Target, &mut <Self::Rt as Deref>::Target)
+    where
+        Self::Lt: DerefMut,
+        Self::Rt: DerefMut;
+}
+
+impl<L: Deref, R: Deref> TrDerefBoth for (L, R) {
+    type Lt = L;
+    type Rt = R;
+
+    #[inline]
+    fn as_deref(&self) -> (&L::Target, &R::Target) {
+        (&self.0, &self.1)
+    }
+
+    #[inline]
+    fn as_deref_mut(&mut self) -> (&mut L::Target, &mut R::Target)
+    where
+        L: DerefMut,
+        R: DerefMut,
+    {
+        (&mut self.0, &mut self.1)
+    }
+}
+
+/// Owned copies of the referenced pair, like `Either::cloned`.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::both::TrClonedBoth;
+///
+/// let s = String::from("a");
+/// let n = 1u8;
+/// assert_eq!((&s, &n).cloned(), (String::from("a"), 1));
+/// ```
+pub trait TrClonedBoth {
+    type Lt;
+    type Rt;
+
+    fn cloned(self) -> (Self::Lt, Self::Rt)
+    where
+        Self::Lt: Clone,
+        Self::Rt: Clone;
+
+    fn copied(self) -> (Self::Lt, Self::Rt)
+    where
+        Self::Lt: Copy,
+        Self::Rt: Copy;
+}
+
+impl<L, R> TrClonedBoth for (&L, &R) {
+    type Lt = L;
+    type Rt = R;
+
+    #[inline]
+    fn cloned(self) -> (L, R)
+    where
+        L: Clone,
+        R: Clone,
+    {
+        (self.0.clone(), self.1.clone())
+    }
+
+    #[inline]
+    fn copied(self) -> (L, R)
+    where
+        L: Copy,
+        R: Copy,
+    {
+        (*self.0, *self.1)
+    }
+}
+
+impl<L, R> TrClonedBoth for (&mut L, &mut R) {
+    type Lt = L;
+    type Rt = R;
+
+    #[inline]
+    fn cloned(self) -> (L, R)
+    where
+        L: Clone,
+        R: Clone,
+    {
+        (self.0.clone(), self.1.clone())
+    }
+
+    #[inline]
+    fn copied(self) -> (L, R)
+    where
+        L: Copy,
+        R: Copy,
+    {
+        (*self.0, *self.1)
+    }
+}
+
 impl<L, R> TrReverseLeftRight for (L, R) {
     type Lt = L;
     type Rt = R;
@@ -13,6 +176,11 @@ impl<L, R> TrReverseLeftRight for (L, R) {
     }
 }
 
+/// Both values are always present. `take_left` and `take_right` drop the
+/// other value, since a pair cannot be left with a hole.
+///
+/// `(Option<L>, Option<R>)` has no impl of its own that reads its options as
+/// the two sides, as it would overlap this one. Convert it to `Any` for that.
 impl<L, R> TrAnyLeftRight for (L, R) {
     type Lt = L;
     type Rt = R;
@@ -71,4 +239,9 @@ impl<L, R> TrAnyLeftRight for (L, R) {
     {
         (&mut self.0, &mut self.1)
     }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        Shape::Both
+    }
 }
@@ -0,0 +1,254 @@
+//! Type-indexed coproducts built from nested `Either`.
+//!
+//! `Coprod!(A, B, C)` is `Either<A, Either<B, Either<C, Never>>>`. Values are
+//! injected and projected by type, and the position of the type is inferred
+//! through the `Here` and `There` index types, so nothing is checked at
+//! runtime. A type that appears more than once cannot be inferred and has to
+//! be addressed with an explicit index, such as `There<Here>`.
+//!
+//! Every coproduct ends in `Never`, so `fold` and `embed` terminate on a type
+//! without values and need no fallback. The handlers for `fold` are a nested
+//! pair built by `hlist!`, one per type in order; closures have to name their
+//! argument type, since nothing else ties a handler to its position.
+//!
+//! # Examples
+//!
+//! ```
+//! use anylr::{coprod::TrCoproduct, hlist, Coprod};
+//!
+//! type Error = Coprod!(core::num::ParseIntError, core::fmt::Error, &'static str);
+//!
+//! let e = Error::inject("bad input");
+//! assert_eq!(e.get::<&str, _>(), Some(&"bad input"));
+//! assert!(e.get::<core::fmt::Error, _>().is_none());
+//!
+//! let rest = e.uninject::<core::fmt::Error, _>().unwrap_err();
+//! let msg = rest.fold(hlist![
+//!     |e: core::num::ParseIntError| e.to_string(),
+//!     |s: &str| s.to_owned(),
+//! ]);
+//! assert_eq!(msg, "bad input");
+//! ```
+
+use core::{convert::Infallible, marker::PhantomData};
+
+use crate::Either;
+
+/// The empty coproduct that terminates every `Coprod!`. It has no values.
+pub type Never = Infallible;
+
+/// Expands to the nested `Either` for a list of types, ending with `Never`.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::{coprod::Never, Coprod, Either};
+///
+/// let _: Coprod!(u8, bool) = Either::<u8, Either<bool, Never>>::Left(1);
+/// ```
+#[macro_export]
+macro_rules! Coprod {
+    () => { $crate::coprod::Never };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        $crate::Either<$head, $crate::Coprod!($($tail),*)>
+    };
+}
+
+/// Index of the first type of a coproduct.
+pub struct Here;
+
+/// Index of a type in the tail of a coproduct.
+pub struct There<I>(PhantomData<I>);
+
+/// Builds a coproduct from a value of one of its types, found at index `I`.
+pub trait TrCoprodInject<T, I> {
+    fn inject(value: T) -> Self;
+}
+
+impl<T, Tail> TrCoprodInject<T, Here> for Either<T, Tail> {
+    fn inject(value: T) -> Self {
+        Either::Left(value)
+    }
+}
+
+impl<H, T, Tail, I> TrCoprodInject<T, There<I>> for Either<H, Tail>
+where
+    Tail: TrCoprodInject<T, I>,
+{
+    fn inject(value: T) -> Self {
+        Either::Right(Tail::inject(value))
+    }
+}
+
+/// Borrows the value of a coproduct if it has the type at index `I`.
+pub trait TrCoprodGet<T, I> {
+    fn get(&self) -> Option<&T>;
+
+    fn get_mut(&mut self) -> Option<&mut T>;
+}
+
+impl<T, Tail> TrCoprodGet<T, Here> for Either<T, Tail> {
+    fn get(&self) -> Option<&T> {
+        self.as_ref().left()
+    }
+
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.as_mut().left()
+    }
+}
+
+impl<H, T, Tail, I> TrCoprodGet<T, There<I>> for Either<H, Tail>
+where
+    Tail: TrCoprodGet<T, I>,
+{
+    fn get(&self) -> Option<&T> {
+        self.as_ref().right().and_then(Tail::get)
+    }
+
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.as_mut().right().and_then(Tail::get_mut)
+    }
+}
+
+/// Takes the value of a coproduct if it has the type at index `I`, or
+/// returns the coproduct of the other types.
+pub trait TrCoprodUninject<T, I> {
+    type Remainder;
+
+    fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+impl<T, Tail> TrCoprodUninject<T, Here> for Either<T, Tail> {
+    type Remainder = Tail;
+
+    fn uninject(self) -> Result<T, Tail> {
+        self.into()
+    }
+}
+
+impl<H, T, Tail, I> TrCoprodUninject<T, There<I>> for Either<H, Tail>
+where
+    Tail: TrCoprodUninject<T, I>,
+{
+    type Remainder = Either<H, Tail::Remainder>;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Either::Left(h) => Result::Err(Either::Left(h)),
+            Either::Right(tail) => tail.uninject().map_err(Either::Right),
+        }
+    }
+}
+
+/// Consumes a coproduct with one handler per type. The handlers are nested
+/// pairs in the order of the types, ending with `()`, as built by `hlist!`.
+pub trait TrCoprodFold<F, Out> {
+    fn fold(self, handlers: F) -> Out;
+}
+
+impl<Out> TrCoprodFold<(), Out> for Never {
+    fn fold(self, _: ()) -> Out {
+        match self {}
+    }
+}
+
+impl<H, Tail, FH, FTail, Out> TrCoprodFold<(FH, FTail,), Out> for Either<H, Tail>
+where
+    FH: FnOnce(H) -> Out,
+    Tail: TrCoprodFold<FTail, Out>,
+{
+    fn fold(self, handlers: (FH, FTail,)) -> Out {
+        let (f, rest,) = handlers;
+        match self {
+            Either::Left(h) => f(h),
+            Either::Right(tail) => tail.fold(rest),
+        }
+    }
+}
+
+/// Converts a coproduct into a wider one that contains all of its types,
+/// found at the indices `I`.
+pub trait TrCoprodEmbed<Target, I> {
+    fn embed(self) -> Target;
+}
+
+impl<Target> TrCoprodEmbed<Target, ()> for Never {
+    fn embed(self) -> Target {
+        match self {}
+    }
+}
+
+impl<H, Tail, Target, IH, ITail> TrCoprodEmbed<Target, (IH, ITail,)> for Either<H, Tail>
+where
+    Target: TrCoprodInject<H, IH>,
+    Tail: TrCoprodEmbed<Target, ITail>,
+{
+    fn embed(self) -> Target {
+        match self {
+            Either::Left(h) => Target::inject(h),
+            Either::Right(tail) => tail.embed(),
+        }
+    }
+}
+
+/// The coproduct operations with the type to look for as the first generic
+/// parameter, so that the index can be left to inference with `_`.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::{coprod::TrCoproduct, Coprod};
+///
+/// type Narrow = Coprod!(u8, bool);
+/// type Wide = Coprod!(char, bool, &'static str, u8);
+///
+/// let w: Wide = Narrow::inject(true).embed();
+/// assert_eq!(w.get::<bool, _>(), Some(&true));
+/// ```
+pub trait TrCoproduct: Sized {
+    fn inject<T, I>(value: T) -> Self
+    where
+        Self: TrCoprodInject<T, I>,
+    {
+        TrCoprodInject::inject(value)
+    }
+
+    fn get<T, I>(&self) -> Option<&T>
+    where
+        Self: TrCoprodGet<T, I>,
+    {
+        TrCoprodGet::get(self)
+    }
+
+    fn get_mut<T, I>(&mut self) -> Option<&mut T>
+    where
+        Self: TrCoprodGet<T, I>,
+    {
+        TrCoprodGet::get_mut(self)
+    }
+
+    fn uninject<T, I>(self) -> Result<T, <Self as TrCoprodUninject<T, I>>::Remainder>
+    where
+        Self: TrCoprodUninject<T, I>,
+    {
+        TrCoprodUninject::uninject(self)
+    }
+
+    fn fold<F, Out>(self, handlers: F) -> Out
+    where
+        Self: TrCoprodFold<F, Out>,
+    {
+        TrCoprodFold::fold(self, handlers)
+    }
+
+    fn embed<Target, I>(self) -> Target
+    where
+        Self: TrCoprodEmbed<Target, I>,
+    {
+        TrCoprodEmbed::embed(self)
+    }
+}
+
+impl<H, Tail> TrCoproduct for Either<H, Tail> {}
+
+impl TrCoproduct for Never {}
@@ -1,6 +1,17 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    iter,
+    option,
+    ops::{ControlFlow, Deref, DerefMut, Index, IndexMut},
+    pin::Pin,
+    str::FromStr,
+};
+
 use crate::{
     abs::{TrAnyLeftRight, TrReverseLeftRight},
-    SomeOf,
+    any_of::AnyLR,
+    some_of::SomeLR,
+    shape, Any, Shape, SomeOf,
 };
 
 #[derive(Clone, Debug)]
@@ -18,6 +29,37 @@ impl<L, R> Either<L, R> {
         Either::Right(r)
     }
 
+    /// Tries to convert `value` into `L`, and then into `R` if that fails.
+    /// Both errors are returned if neither conversion succeeds.
+    ///
+    /// `TryFrom` consumes its argument and does not hand it back on failure,
+    /// so `value` is cloned for the first attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<u8, i8>::try_from_either(-3i32).unwrap();
+    /// assert_eq!(e.right(), Some(-3));
+    /// assert!(Either::<u8, i8>::try_from_either(1000i32).unwrap_err().is_both());
+    /// ```
+    pub fn try_from_either<T>(value: T) -> Result<Self, SomeOf<L::Error, R::Error>>
+    where
+        T: Clone,
+        L: TryFrom<T>,
+        R: TryFrom<T>,
+    {
+        let l_err = match L::try_from(value.clone()) {
+            Result::Ok(l) => return Result::Ok(Either::Left(l)),
+            Result::Err(e) => e,
+        };
+        match R::try_from(value) {
+            Result::Ok(r) => Result::Ok(Either::Right(r)),
+            Result::Err(r_err) => Result::Err(SomeOf::new_both(l_err, r_err)),
+        }
+    }
+
     pub fn split(self) -> (Option<L>, Option<R>) {
         match self {
             Either::Left(l) => (Option::Some(l), Option::None),
@@ -75,6 +117,231 @@ impl<L, R> Either<L, R> {
         }
     }
 
+    /// Projects a pinned reference to a pinned reference of the contained
+    /// value.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Either<Pin<&L>, Pin<&R>> {
+        // SAFETY: See `as_pin_mut`.
+        unsafe {
+            match self.get_ref() {
+                Either::Left(l) => Either::Left(Pin::new_unchecked(l)),
+                Either::Right(r) => Either::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Projects a pinned mutable reference to a pinned mutable reference of
+    /// the contained value.
+    ///
+    /// # Pinning
+    ///
+    /// The values are structurally pinned, which is sound because:
+    ///
+    /// - `Either<L, R>` is `Unpin` only if both `L` and `R` are `Unpin`, as
+    ///   the auto trait is not implemented by hand;
+    /// - `Either<L, R>` does not implement `Drop`, so the contained value is
+    ///   dropped in place by its own destructor;
+    /// - every method that may move the value out needs `self` or a
+    ///   `&mut Either<L, R>`, neither of which can be obtained from a
+    ///   `Pin<&mut Either<L, R>>` unless the whole value is `Unpin`;
+    /// - `Either<L, R>` is not `#[repr(packed)]`.
+    ///
+    /// The same reasoning applies to `SomeOf<L, R>`, `Any<L, R>` and the pair
+    /// `(L, R)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{marker::PhantomPinned, pin::pin};
+    /// use anylr::Either;
+    ///
+    /// struct Fut(u32, PhantomPinned);
+    ///
+    /// let mut e = pin!(Either::<Fut, u8>::Left(Fut(1, PhantomPinned)));
+    /// let addr = match e.as_ref().as_pin_ref() {
+    ///     Either::Left(f) => &f.0 as *const u32,
+    ///     Either::Right(_) => unreachable!(),
+    /// };
+    /// if let Either::Left(f) = e.as_mut().as_pin_mut() {
+    ///     // SAFETY: `u32` is not structurally pinned in `Fut`.
+    ///     unsafe { f.get_unchecked_mut().0 += 1 };
+    /// }
+    /// let Either::Left(f) = e.as_ref().as_pin_ref() else { unreachable!() };
+    /// assert_eq!(f.0, 2);
+    /// assert_eq!(&f.0 as *const u32, addr);
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Either<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: The contained value is structurally pinned, see above.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Either::Left(l) => Either::Left(Pin::new_unchecked(l)),
+                Either::Right(r) => Either::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Calls `f` with a reference to the left value if present, and returns
+    /// `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let mut seen = None;
+    /// let e = Either::<u8, char>::Left(1).inspect_left(|l| seen = Some(*l));
+    /// assert_eq!((e.left(), seen), (Some(1), Some(1)));
+    /// ```
+    pub fn inspect_left<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&L),
+    {
+        if let Option::Some(l) = self.as_ref().split().0 {
+            f(l)
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the right value if present, and returns
+    /// `self` untouched.
+    pub fn inspect_right<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&R),
+    {
+        if let Option::Some(r) = self.as_ref().split().1 {
+            f(r)
+        }
+        self
+    }
+
+    /// Returns `self` untouched. An `Either` never holds both values, so `f`
+    /// is never called; this is only here to match `Any` and `SomeOf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<u8, char>::Left(1).inspect_both(|_, _| unreachable!());
+    /// assert_eq!(e.left(), Some(1));
+    /// ```
+    pub fn inspect_both<F>(self, _f: F) -> Self
+    where
+        F: FnOnce(&L, &R),
+    {
+        self
+    }
+
+    /// Returns `None` if it is a `Left` whose value does not satisfy `pred`,
+    /// and the value untouched otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<u8, char>::Left(1);
+    /// assert!(e.filter_left(|l| *l > 1).is_none());
+    /// assert!(e.filter_right(|_| false).is_some());
+    /// ```
+    pub fn filter_left<P>(self, pred: P) -> Option<Either<L, R>>
+    where
+        P: FnOnce(&L) -> bool,
+    {
+        match self {
+            Either::Left(l) => pred(&l).then_some(Either::Left(l)),
+            Either::Right(r) => Option::Some(Either::Right(r)),
+        }
+    }
+
+    /// Returns `None` if it is a `Right` whose value does not satisfy `pred`,
+    /// and the value untouched otherwise.
+    pub fn filter_right<P>(self, pred: P) -> Option<Either<L, R>>
+    where
+        P: FnOnce(&R) -> bool,
+    {
+        match self {
+            Either::Left(l) => Option::Some(Either::Left(l)),
+            Either::Right(r) => pred(&r).then_some(Either::Right(r)),
+        }
+    }
+
+    /// Maps a `Left` value with `f`, returning `None` if `f` does.
+    pub fn filter_map_left<F, U>(self, f: F) -> Option<Either<U, R>>
+    where
+        F: FnOnce(L) -> Option<U>,
+    {
+        match self {
+            Either::Left(l) => f(l).map(Either::Left),
+            Either::Right(r) => Option::Some(Either::Right(r)),
+        }
+    }
+
+    /// Maps a `Right` value with `f`, returning `None` if `f` does.
+    pub fn filter_map_right<F, U>(self, f: F) -> Option<Either<L, U>>
+    where
+        F: FnOnce(R) -> Option<U>,
+    {
+        match self {
+            Either::Left(l) => Option::Some(Either::Left(l)),
+            Either::Right(r) => f(r).map(Either::Right),
+        }
+    }
+
+    /// Pairs up two `Left` values, or returns the first `Right` value found,
+    /// like `Result::zip`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let a = Either::<u8, &str>::Left(1);
+    /// assert_eq!(a.zip_left(Either::Left('a')).left(), Some((1, 'a')));
+    /// assert_eq!(a.zip_left(Either::<char, _>::Right("e")).right(), Some("e"));
+    /// ```
+    pub fn zip_left<L2>(self, other: Either<L2, R>) -> Either<(L, L2), R> {
+        match (self, other) {
+            (Either::Left(l1), Either::Left(l2)) => Either::Left((l1, l2)),
+            (Either::Right(r), _) | (_, Either::Right(r)) => Either::Right(r),
+        }
+    }
+
+    /// Pairs up two `Right` values, or returns the first `Left` value found.
+    pub fn zip_right<R2>(self, other: Either<L, R2>) -> Either<L, (R, R2)> {
+        match (self, other) {
+            (Either::Right(r1), Either::Right(r2)) => Either::Right((r1, r2)),
+            (Either::Left(l), _) | (_, Either::Left(l)) => Either::Left(l),
+        }
+    }
+
+    /// Converts from `&Either<L, R>` to `Either<&L::Target, &R::Target>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<String, Vec<u8>>::Left("hello".to_owned());
+    /// let s: Either<&str, &[u8]> = e.as_deref();
+    /// assert_eq!(s.left(), Some("hello"));
+    /// ```
+    pub fn as_deref(&self) -> Either<&L::Target, &R::Target>
+    where
+        L: Deref,
+        R: Deref,
+    {
+        self.as_ref().map_left(Deref::deref).map_right(Deref::deref)
+    }
+
+    /// Converts from `&mut Either<L, R>` to `Either<&mut L::Target, &mut R::Target>`.
+    pub fn as_deref_mut(&mut self) -> Either<&mut L::Target, &mut R::Target>
+    where
+        L: DerefMut,
+        R: DerefMut,
+    {
+        self.as_mut().map_left(DerefMut::deref_mut).map_right(DerefMut::deref_mut)
+    }
+
     pub fn left(self) -> Option<L> {
         if let Either::Left(l) = self {
             Option::Some(l)
@@ -99,16 +366,27 @@ impl<L, R> Either<L, R> {
     }
 
     pub const fn is_left(&self) -> bool {
-        match self {
-            Either::Left(_) => true,
-            _ => false,
-        }
+        matches!(self, Either::Left(_))
     }
 
     pub const fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// The same as `is_left`
+    pub const fn contains_left(&self) -> bool {
+        self.is_left()
+    }
+
+    /// The same as `is_right`
+    pub const fn contains_right(&self) -> bool {
+        self.is_right()
+    }
+
+    pub const fn shape(&self) -> Shape {
         match self {
-            Either::Right(_) => true,
-            _ => false,
+            Either::Left(_) => Shape::Left,
+            Either::Right(_) => Shape::Right,
         }
     }
 
@@ -133,6 +411,292 @@ impl<L, R> Either<L, R> {
             false
         }
     }
+
+    /// Returns the left value, or panics if it is a `Right`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `Right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// assert_eq!(Either::<u8, String>::Left(1).unwrap_left(), 1);
+    ///
+    /// // Use the defaulting accessors when the value may be a `Right`.
+    /// let e = Either::<u8, String>::Right("x".to_owned());
+    /// assert_eq!(e.clone().left_or_else(|r| r.len() as u8), 1);
+    /// assert_eq!(e.left_or_default(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use anylr::Either;
+    ///
+    /// // panics with "called `Either::unwrap_left()` on a value of shape `Right`"
+    /// Either::<u8, String>::Right("x".to_owned()).unwrap_left();
+    /// ```
+    #[track_caller]
+    pub fn unwrap_left(self) -> L {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(_) => shape::unwrap_failed("Either::unwrap_left()", Shape::Right),
+        }
+    }
+
+    /// Returns the right value, or panics if it is a `Left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `Left`.
+    #[track_caller]
+    pub fn unwrap_right(self) -> R {
+        match self {
+            Either::Left(_) => shape::unwrap_failed("Either::unwrap_right()", Shape::Left),
+            Either::Right(r) => r,
+        }
+    }
+
+    /// Returns the left value, or panics with `msg` if it is a `Right`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `Right`.
+    #[track_caller]
+    pub fn expect_left(self, msg: &str) -> L {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(_) => shape::expect_failed(msg, Shape::Right),
+        }
+    }
+
+    /// Returns the right value, or panics with `msg` if it is a `Left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `Left`.
+    #[track_caller]
+    pub fn expect_right(self, msg: &str) -> R {
+        match self {
+            Either::Left(_) => shape::expect_failed(msg, Shape::Left),
+            Either::Right(r) => r,
+        }
+    }
+
+    /// Returns the left value, or `default` if it is a `Right`.
+    pub fn left_or(self, default: L) -> L {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(_) => default,
+        }
+    }
+
+    /// Returns the left value, or computes one from the right value with `f`.
+    pub fn left_or_else<F>(self, f: F) -> L
+    where
+        F: FnOnce(R) -> L,
+    {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => f(r),
+        }
+    }
+
+    /// Returns the left value, or `L::default()` if it is a `Right`.
+    pub fn left_or_default(self) -> L
+    where
+        L: Default,
+    {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(_) => L::default(),
+        }
+    }
+
+    /// Returns the right value, or `default` if it is a `Left`.
+    pub fn right_or(self, default: R) -> R {
+        match self {
+            Either::Left(_) => default,
+            Either::Right(r) => r,
+        }
+    }
+
+    /// Returns the right value, or computes one from the left value with `f`.
+    pub fn right_or_else<F>(self, f: F) -> R
+    where
+        F: FnOnce(L) -> R,
+    {
+        match self {
+            Either::Left(l) => f(l),
+            Either::Right(r) => r,
+        }
+    }
+
+    /// Returns the right value, or `R::default()` if it is a `Left`.
+    pub fn right_or_default(self) -> R
+    where
+        R: Default,
+    {
+        match self {
+            Either::Left(_) => R::default(),
+            Either::Right(r) => r,
+        }
+    }
+}
+
+impl<L: Copy, R: Copy> Either<L, R> {
+    /// The same as `split`, but usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Either, Shape};
+    ///
+    /// const E: Either<u8, char> = Either::new_right('a');
+    /// const R: Either<char, u8> = E.to_reversed();
+    /// const _: () = assert!(E.is_right() && R.is_left());
+    /// const _: () = assert!(matches!(R.shape(), Shape::Left));
+    /// const SPLIT: (Option<char>, Option<u8>) = R.to_split();
+    /// assert_eq!(SPLIT, (Some('a'), None));
+    /// ```
+    pub const fn to_split(self) -> (Option<L>, Option<R>) {
+        match self {
+            Either::Left(l) => (Option::Some(l), Option::None),
+            Either::Right(r) => (Option::None, Option::Some(r)),
+        }
+    }
+
+    /// The same as `reverse`, but usable in const contexts.
+    pub const fn to_reversed(self) -> Either<R, L> {
+        match self {
+            Either::Left(x) => Either::Right(x),
+            Either::Right(x) => Either::Left(x),
+        }
+    }
+}
+
+impl<L, R> Either<&L, &R> {
+    /// Maps a `Either<&L, &R>` to a `Either<L, R>` by cloning the contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let s = String::from("hello");
+    /// let e = Either::<&String, &u8>::Left(&s);
+    /// assert_eq!(e.cloned().left(), Some(s.clone()));
+    /// ```
+    pub fn cloned(self) -> Either<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(L::clone).map_right(R::clone)
+    }
+
+    /// Maps a `Either<&L, &R>` to a `Either<L, R>` by copying the contents.
+    pub fn copied(self) -> Either<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<L, R> Either<&mut L, &mut R> {
+    /// Maps a `Either<&mut L, &mut R>` to a `Either<L, R>` by cloning the contents.
+    pub fn cloned(self) -> Either<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(|l| l.clone()).map_right(|r| r.clone())
+    }
+
+    /// Maps a `Either<&mut L, &mut R>` to a `Either<L, R>` by copying the contents.
+    pub fn copied(self) -> Either<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<A, B, C, D> Either<(A, B), (C, D)> {
+    /// Splits an `Either` of pairs into a pair of `Either`s of the same side.
+    pub fn unzip(self) -> (Either<A, C>, Either<B, D>) {
+        match self {
+            Either::Left((a, b)) => (Either::Left(a), Either::Left(b)),
+            Either::Right((c, d)) => (Either::Right(c), Either::Right(d)),
+        }
+    }
+}
+
+impl<A, B, C> Either<A, Either<B, C>> {
+    /// Re-associates `A + (B + C)` as `(A + B) + C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<u8, Either<char, &str>>::Right(Either::Left('b'));
+    /// let e = e.assoc_left();
+    /// assert!(matches!(e, Either::Left(Either::Right('b'))));
+    /// assert!(matches!(e.assoc_right(), Either::Right(Either::Left('b'))));
+    /// ```
+    pub fn assoc_left(self) -> Either<Either<A, B>, C> {
+        match self {
+            Either::Left(a) => Either::Left(Either::Left(a)),
+            Either::Right(Either::Left(b)) => Either::Left(Either::Right(b)),
+            Either::Right(Either::Right(c)) => Either::Right(c),
+        }
+    }
+}
+
+impl<A, B, C> Either<Either<A, B>, C> {
+    /// Re-associates `(A + B) + C` as `A + (B + C)`.
+    pub fn assoc_right(self) -> Either<A, Either<B, C>> {
+        match self {
+            Either::Left(Either::Left(a)) => Either::Left(a),
+            Either::Left(Either::Right(b)) => Either::Right(Either::Left(b)),
+            Either::Right(c) => Either::Right(Either::Right(c)),
+        }
+    }
+}
+
+impl<A, B, C> Either<(A, B), (A, C)> {
+    /// Distributes `A * (B + C)` as `A * B + A * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::distribute((1u8, Either::<char, &str>::Right("c")));
+    /// assert_eq!(e.clone().right(), Some((1, "c")));
+    /// let (a, e) = e.factor();
+    /// assert_eq!((a, e.right()), (1, Some("c")));
+    /// ```
+    pub fn distribute(pair: (A, Either<B, C>)) -> Self {
+        match pair {
+            (a, Either::Left(b)) => Either::Left((a, b)),
+            (a, Either::Right(c)) => Either::Right((a, c)),
+        }
+    }
+
+    /// Factors `A * B + A * C` out as `A * (B + C)`, the inverse of
+    /// `distribute`.
+    pub fn factor(self) -> (A, Either<B, C>) {
+        match self {
+            Either::Left((a, b)) => (a, Either::Left(b)),
+            Either::Right((a, c)) => (a, Either::Right(c)),
+        }
+    }
 }
 
 impl<T> Either<T, T> {
@@ -142,6 +706,210 @@ impl<T> Either<T, T> {
             Either::Right(x) => x,
         }
     }
+
+    /// Applies the same function to the contained value, whichever side it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::Either;
+    ///
+    /// let e = Either::<u8, u8>::Right(2).map_both(|x| x * 10);
+    /// assert_eq!(e.right(), Some(20));
+    /// ```
+    pub fn map_both<F, U>(self, f: F) -> Either<U, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(f(r)),
+        }
+    }
+
+    /// Iterates over references to the present values, from left to right.
+    pub fn iter(&self) -> iter::Chain<option::IntoIter<&T>, option::IntoIter<&T>> {
+        self.as_ref().into_iter()
+    }
+
+    /// Iterates over mutable references to the present values, from left to
+    /// right.
+    pub fn iter_mut(&mut self) -> iter::Chain<option::IntoIter<&mut T>, option::IntoIter<&mut T>> {
+        self.as_mut().into_iter()
+    }
+
+    /// Puts the left value, if any, in the first slot and the right value, if
+    /// any, in the second slot.
+    pub fn into_array(self) -> [Option<T>; 2] {
+        let (l, r) = self.split();
+        [l, r]
+    }
+}
+
+impl<T> IntoIterator for Either<T, T> {
+    type Item = T;
+    type IntoIter = iter::Chain<option::IntoIter<T>, option::IntoIter<T>>;
+
+    /// Iterates over the present values, from left to right.
+    fn into_iter(self) -> Self::IntoIter {
+        let (l, r) = self.split();
+        l.into_iter().chain(r)
+    }
+}
+
+/// Dereferences whichever side is present, when both sides share a target.
+///
+/// `Deref`, `AsRef`, `Index` and their `mut` counterparts stay generic over
+/// the target and only require `R` to agree with `L` on it. `Borrow` is
+/// implemented for a fixed list of targets instead, see `impl_borrow!`.
+///
+/// # Examples
+///
+/// ```
+/// use core::borrow::Borrow;
+/// use anylr::Either;
+///
+/// let mut e = Either::<String, Box<str>>::Right("hello".into());
+/// assert_eq!(e.len(), 5);
+/// e.make_ascii_uppercase();
+/// assert_eq!(&*e, "HELLO");
+/// assert_eq!(Borrow::<str>::borrow(&e), "HELLO");
+///
+/// let v = Either::<Vec<u8>, [u8; 3]>::Right([1, 2, 3]);
+/// assert_eq!(v[1], 2);
+/// assert_eq!(AsRef::<[u8]>::as_ref(&v), &[1, 2, 3]);
+/// ```
+impl<L, R> Deref for Either<L, R>
+where
+    L: Deref,
+    R: Deref<Target = L::Target>,
+{
+    type Target = L::Target;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => r,
+        }
+    }
+}
+
+impl<L, R> DerefMut for Either<L, R>
+where
+    L: DerefMut,
+    R: DerefMut<Target = L::Target>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => r,
+        }
+    }
+}
+
+impl<T: ?Sized, L: AsRef<T>, R: AsRef<T>> AsRef<T> for Either<L, R> {
+    fn as_ref(&self) -> &T {
+        match self {
+            Either::Left(l) => l.as_ref(),
+            Either::Right(r) => r.as_ref(),
+        }
+    }
+}
+
+impl<T: ?Sized, L: AsMut<T>, R: AsMut<T>> AsMut<T> for Either<L, R> {
+    fn as_mut(&mut self) -> &mut T {
+        match self {
+            Either::Left(l) => l.as_mut(),
+            Either::Right(r) => r.as_mut(),
+        }
+    }
+}
+
+/// A blanket `Borrow<T>` would overlap with `impl<T> Borrow<T> for T` at
+/// `T = Either<L, R>`, which coherence rejects, so the unsized targets that
+/// owned strings and collections borrow as are implemented one by one. More
+/// can be added to the list as needed.
+macro_rules! impl_borrow {
+    ($(<$($g:ident),*> $t:ty),* $(,)?) => {$(
+        impl<$($g,)* L: Borrow<$t>, R: Borrow<$t>> Borrow<$t> for Either<L, R> {
+            fn borrow(&self) -> &$t {
+                match self {
+                    Either::Left(l) => l.borrow(),
+                    Either::Right(r) => r.borrow(),
+                }
+            }
+        }
+
+        impl<$($g,)* L: BorrowMut<$t>, R: BorrowMut<$t>> BorrowMut<$t> for Either<L, R> {
+            fn borrow_mut(&mut self) -> &mut $t {
+                match self {
+                    Either::Left(l) => l.borrow_mut(),
+                    Either::Right(r) => r.borrow_mut(),
+                }
+            }
+        }
+    )*};
+}
+
+impl_borrow!(<> str, <T> [T]);
+
+impl<I, L, R> Index<I> for Either<L, R>
+where
+    L: Index<I>,
+    R: Index<I, Output = L::Output>,
+{
+    type Output = L::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        match self {
+            Either::Left(l) => &l[index],
+            Either::Right(r) => &r[index],
+        }
+    }
+}
+
+impl<I, L, R> IndexMut<I> for Either<L, R>
+where
+    L: IndexMut<I>,
+    R: IndexMut<I, Output = L::Output>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        match self {
+            Either::Left(l) => &mut l[index],
+            Either::Right(r) => &mut r[index],
+        }
+    }
+}
+
+/// Parses `L` first and falls back to `R`, so the left type wins when both
+/// could parse the string. Both errors are returned if neither parses.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::Either;
+///
+/// let port: Either<u16, String> = "8080".parse().unwrap();
+/// assert_eq!(port.left(), Some(8080));
+///
+/// let name: Either<u16, String> = "http".parse().unwrap();
+/// assert_eq!(name.right().as_deref(), Some("http"));
+///
+/// assert!("x".parse::<Either<u16, bool>>().unwrap_err().is_both());
+/// ```
+impl<L: FromStr, R: FromStr> FromStr for Either<L, R> {
+    type Err = SomeOf<L::Err, R::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let l_err = match L::from_str(s) {
+            Result::Ok(l) => return Result::Ok(Either::Left(l)),
+            Result::Err(e) => e,
+        };
+        match R::from_str(s) {
+            Result::Ok(r) => Result::Ok(Either::Right(r)),
+            Result::Err(r_err) => Result::Err(SomeOf::new_both(l_err, r_err)),
+        }
+    }
 }
 
 impl<L> From<Option<L>> for Either<L, ()> {
@@ -153,6 +921,12 @@ impl<L> From<Option<L>> for Either<L, ()> {
     }
 }
 
+impl<L> From<Either<L, ()>> for Option<L> {
+    fn from(value: Either<L, ()>) -> Self {
+        value.left()
+    }
+}
+
 impl<T, E> From<Result<T, E>> for Either<T, E> {
     fn from(value: Result<T, E>) -> Self {
         match value {
@@ -162,6 +936,91 @@ impl<T, E> From<Result<T, E>> for Either<T, E> {
     }
 }
 
+impl<T, E> From<Either<T, E>> for Result<T, E> {
+    fn from(value: Either<T, E>) -> Self {
+        match value {
+            Either::Left(t) => Result::Ok(t),
+            Either::Right(e) => Result::Err(e),
+        }
+    }
+}
+
+/// `Continue` becomes `Left` and `Break` becomes `Right`.
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::ControlFlow;
+/// use anylr::Either;
+///
+/// let e = Either::from(ControlFlow::<&str, u8>::Break("stop"));
+/// assert!(matches!(e, Either::Right("stop")));
+/// assert_eq!(ControlFlow::from(e), ControlFlow::<&str, u8>::Break("stop"));
+/// ```
+impl<B, C> From<ControlFlow<B, C>> for Either<C, B> {
+    fn from(value: ControlFlow<B, C>) -> Self {
+        match value {
+            ControlFlow::Continue(c) => Either::Left(c),
+            ControlFlow::Break(b) => Either::Right(b),
+        }
+    }
+}
+
+impl<B, C> From<Either<C, B>> for ControlFlow<B, C> {
+    fn from(value: Either<C, B>) -> Self {
+        match value {
+            Either::Left(c) => ControlFlow::Continue(c),
+            Either::Right(b) => ControlFlow::Break(b),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for (Option<L>, Option<R>,) {
+    fn from(value: Either<L, R>) -> Self {
+        value.split()
+    }
+}
+
+/// Fails with the original value if it is `Both`.
+impl<L, R> TryFrom<SomeOf<L, R>> for Either<L, R> {
+    type Error = SomeOf<L, R>;
+
+    fn try_from(value: SomeOf<L, R>) -> Result<Self, Self::Error> {
+        match value.into_inner() {
+            SomeLR::Left(l) => Result::Ok(Either::Left(l)),
+            SomeLR::Right(r) => Result::Ok(Either::Right(r)),
+            SomeLR::Both((l, r,)) => Result::Err(SomeOf::new_both(l, r)),
+        }
+    }
+}
+
+/// Fails with the original value if it is `Neither` or `Both`.
+impl<L, R> TryFrom<Any<L, R>> for Either<L, R> {
+    type Error = Any<L, R>;
+
+    fn try_from(value: Any<L, R>) -> Result<Self, Self::Error> {
+        match value.into_inner() {
+            AnyLR::Left(l) => Result::Ok(Either::Left(l)),
+            AnyLR::Right(r) => Result::Ok(Either::Right(r)),
+            AnyLR::Neither => Result::Err(Any::new_neither()),
+            AnyLR::Both((l, r,)) => Result::Err(Any::new_both(l, r)),
+        }
+    }
+}
+
+/// Fails with the original value unless exactly one is `Some`.
+impl<L, R> TryFrom<(Option<L>, Option<R>,)> for Either<L, R> {
+    type Error = (Option<L>, Option<R>,);
+
+    fn try_from(value: (Option<L>, Option<R>,)) -> Result<Self, Self::Error> {
+        match value {
+            (Option::Some(l), Option::None) => Result::Ok(Either::Left(l)),
+            (Option::None, Option::Some(r)) => Result::Ok(Either::Right(r)),
+            x => Result::Err(x),
+        }
+    }
+}
+
 impl<L, R> TrReverseLeftRight for Either<L, R> {
     type Lt = L;
     type Rt = R;
@@ -232,13 +1091,8 @@ impl<L, R> TrAnyLeftRight for Either<L, R> {
     }
 
     #[inline]
-    fn contains_left(&self) -> bool {
-        Either::is_left(self)
-    }
-
-    #[inline]
-    fn contains_right(&self) -> bool {
-        Either::is_right(self)
+    fn shape(&self) -> Shape {
+        Either::shape(self)
     }
 }
 
@@ -0,0 +1,520 @@
+//! `#[repr(C)]` mirrors of `Either<L, R>`, `Any<L, R>` and `SomeOf<L, R>`
+//! that can be passed across an FFI boundary.
+//!
+//! The layouts are fixed and equivalent to the following C declarations,
+//! where `L` and `R` are themselves FFI-safe types:
+//!
+//! ```c
+//! /* CEither<L, R> */
+//! struct CEither {
+//!     uint8_t tag;        /* 0: left, 1: right, no other value is valid */
+//!     union {
+//!         L left;
+//!         R right;
+//!     } payload;          /* only the member selected by `tag` is initialized */
+//! };
+//!
+//! /* CAny<L, R>, also used for SomeOf<L, R> */
+//! struct CAny {
+//!     uint8_t flags;      /* bit 0: left is present, bit 1: right is present,
+//!                            other bits must be zero and are rejected */
+//!     L left;             /* initialized only if bit 0 is set */
+//!     R right;            /* initialized only if bit 1 is set */
+//! };
+//! ```
+//!
+//! Values written by C should be taken with `CEither::try_from_raw` and
+//! `CAny::try_from_raw`, which reject a tag other than `0` or `1` and flags
+//! with any bit other than bits 0 and 1 set.
+//!
+//! Both types own their payloads: the present values are dropped along with
+//! them, and the conversions from and to the Rust shapes move the payloads
+//! without copying the other (uninitialized) slots.
+
+use core::{
+    fmt,
+    mem::{ManuallyDrop, MaybeUninit},
+};
+
+use crate::{
+    any_of::AnyLR,
+    Any, Either, Shape, SomeOf,
+};
+
+/// The discriminant of `CEither<L, R>`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CEitherTag {
+    Left = 0,
+    Right = 1,
+}
+
+impl CEitherTag {
+    /// Returns `None` if `tag` is neither `0` nor `1`.
+    pub const fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Option::Some(CEitherTag::Left),
+            1 => Option::Some(CEitherTag::Right),
+            _ => Option::None,
+        }
+    }
+}
+
+#[repr(C)]
+union CEitherPayload<L, R> {
+    left: ManuallyDrop<L>,
+    right: ManuallyDrop<R>,
+}
+
+/// The C-compatible layout of `Either<L, R>`, a tag followed by a union.
+///
+/// The tag is kept as a raw `u8`, so a value written by C with any tag byte
+/// can be held without undefined behaviour. Values taken from C should go
+/// through [`CEither::try_from_raw`], which checks the tag; the other methods
+/// panic on a tag that is neither `0` nor `1`.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::{align_of, size_of};
+/// use anylr::{ffi::{CEither, CEitherTag}, Either};
+///
+/// assert_eq!(size_of::<CEither<u32, u64>>(), 16);
+/// assert_eq!(align_of::<CEither<u32, u64>>(), 8);
+/// assert_eq!(size_of::<CEither<u8, u16>>(), 4);
+///
+/// let c = CEither::<u32, u64>::from(Either::Right(42u64));
+/// assert_eq!(c.tag(), CEitherTag::Right);
+/// assert_eq!(c.as_either().right(), Some(&42));
+/// assert_eq!(Either::from(c).right(), Some(42));
+/// ```
+#[repr(C)]
+pub struct CEither<L, R> {
+    tag: u8,
+    payload: CEitherPayload<L, R>,
+}
+
+impl<L, R> CEither<L, R> {
+    pub const fn new_left(l: L) -> Self {
+        CEither {
+            tag: CEitherTag::Left as u8,
+            payload: CEitherPayload { left: ManuallyDrop::new(l) },
+        }
+    }
+
+    pub const fn new_right(r: R) -> Self {
+        CEither {
+            tag: CEitherTag::Right as u8,
+            payload: CEitherPayload { right: ManuallyDrop::new(r) },
+        }
+    }
+
+    /// Moves a `CEither` out of `ptr`, checking its tag first. Fails with the
+    /// raw tag if it is neither `0` nor `1`, in which case nothing is read or
+    /// moved.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and properly aligned. If the tag is
+    /// valid, the member of the union it selects must be initialized, and
+    /// the value is moved out: the caller must not use or drop it through
+    /// `ptr` again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use anylr::ffi::CEither;
+    ///
+    /// let c = CEither::<u32, u64>::new_left(7);
+    /// let e = unsafe { CEither::try_from_raw(&c) }.unwrap();
+    /// core::mem::forget(c);
+    /// assert_eq!(e.as_either().left(), Some(&7));
+    ///
+    /// let mut bad = MaybeUninit::<CEither<u32, u64>>::zeroed();
+    /// unsafe { bad.as_mut_ptr().cast::<u8>().write(2) };
+    /// assert_eq!(unsafe { CEither::try_from_raw(bad.as_ptr()) }.err(), Some(2));
+    /// ```
+    pub unsafe fn try_from_raw(ptr: *const Self) -> Result<Self, u8> {
+        // SAFETY: The caller guarantees `ptr` is valid for reads and aligned,
+        // and the tag is the first byte of the `repr(C)` struct.
+        let tag = unsafe { ptr.cast::<u8>().read() };
+        match CEitherTag::from_u8(tag) {
+            // SAFETY: The tag is valid, so the caller guarantees the member
+            // it selects is initialized and hands over ownership.
+            Option::Some(_) => Result::Ok(unsafe { ptr.read() }),
+            Option::None => Result::Err(tag),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the tag is neither `0` nor `1`.
+    pub const fn tag(&self) -> CEitherTag {
+        match CEitherTag::from_u8(self.tag) {
+            Option::Some(tag) => tag,
+            Option::None => panic!("invalid CEither tag"),
+        }
+    }
+
+    /// Borrows the payload as an `Either<&L, &R>`, without moving it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tag is neither `0` nor `1`.
+    pub fn as_either(&self) -> Either<&L, &R> {
+        // SAFETY: The tag selects the initialized field of the union.
+        unsafe {
+            match self.tag() {
+                CEitherTag::Left => Either::Left(&self.payload.left),
+                CEitherTag::Right => Either::Right(&self.payload.right),
+            }
+        }
+    }
+
+    /// Borrows the payload as an `Either<&mut L, &mut R>`, without moving it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tag is neither `0` nor `1`.
+    pub fn as_either_mut(&mut self) -> Either<&mut L, &mut R> {
+        // SAFETY: The tag selects the initialized field of the union.
+        unsafe {
+            match self.tag() {
+                CEitherTag::Left => Either::Left(&mut self.payload.left),
+                CEitherTag::Right => Either::Right(&mut self.payload.right),
+            }
+        }
+    }
+}
+
+impl<L, R> Drop for CEither<L, R> {
+    /// Leaks the payload if the tag is not valid, as it is then unknown which
+    /// member is initialized.
+    fn drop(&mut self) {
+        // SAFETY: The tag selects the initialized field of the union, and it
+        // is never accessed again.
+        unsafe {
+            match CEitherTag::from_u8(self.tag) {
+                Option::Some(CEitherTag::Left) => ManuallyDrop::drop(&mut self.payload.left),
+                Option::Some(CEitherTag::Right) => ManuallyDrop::drop(&mut self.payload.right),
+                Option::None => {}
+            }
+        }
+    }
+}
+
+impl<L: Clone, R: Clone> Clone for CEither<L, R> {
+    fn clone(&self) -> Self {
+        match self.as_either() {
+            Either::Left(l) => CEither::new_left(l.clone()),
+            Either::Right(r) => CEither::new_right(r.clone()),
+        }
+    }
+}
+
+impl<L: fmt::Debug, R: fmt::Debug> fmt::Debug for CEither<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CEither").field(&self.as_either()).finish()
+    }
+}
+
+impl<L, R> From<Either<L, R>> for CEither<L, R> {
+    fn from(value: Either<L, R>) -> Self {
+        match value {
+            Either::Left(l) => CEither::new_left(l),
+            Either::Right(r) => CEither::new_right(r),
+        }
+    }
+}
+
+impl<L, R> From<CEither<L, R>> for Either<L, R> {
+    /// # Panics
+    ///
+    /// Panics if the tag is neither `0` nor `1`.
+    fn from(value: CEither<L, R>) -> Self {
+        let mut value = ManuallyDrop::new(value);
+        // SAFETY: The tag selects the initialized field of the union, and
+        // `value` will not be dropped.
+        unsafe {
+            match value.tag() {
+                CEitherTag::Left => Either::Left(ManuallyDrop::take(&mut value.payload.left)),
+                CEitherTag::Right => Either::Right(ManuallyDrop::take(&mut value.payload.right)),
+            }
+        }
+    }
+}
+
+/// The C-compatible layout of `Any<L, R>` and `SomeOf<L, R>`, the presence
+/// bitflags followed by the slots of both sides.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::{align_of, size_of};
+/// use anylr::{ffi::CAny, Any, SomeOf};
+///
+/// assert_eq!(size_of::<CAny<u32, u64>>(), 16);
+/// assert_eq!(align_of::<CAny<u32, u64>>(), 8);
+/// assert_eq!(size_of::<CAny<u8, u8>>(), 3);
+///
+/// let c = CAny::<u32, u64>::from(Any::new_both(1u32, 2u64));
+/// assert_eq!(c.flags(), CAny::<u32, u64>::LEFT | CAny::<u32, u64>::RIGHT);
+/// assert_eq!(c.left(), Some(&1));
+///
+/// let c = CAny::<u32, u64>::new_neither();
+/// assert!(SomeOf::try_from(c).is_err());
+/// ```
+#[repr(C)]
+pub struct CAny<L, R> {
+    flags: u8,
+    left: MaybeUninit<L>,
+    right: MaybeUninit<R>,
+}
+
+impl<L, R> CAny<L, R> {
+    /// The bit of `flags` telling the left value is present.
+    pub const LEFT: u8 = Shape::Left.bits();
+
+    /// The bit of `flags` telling the right value is present.
+    pub const RIGHT: u8 = Shape::Right.bits();
+
+    pub const fn new_left(l: L) -> Self {
+        CAny {
+            flags: Self::LEFT,
+            left: MaybeUninit::new(l),
+            right: MaybeUninit::uninit(),
+        }
+    }
+
+    pub const fn new_right(r: R) -> Self {
+        CAny {
+            flags: Self::RIGHT,
+            left: MaybeUninit::uninit(),
+            right: MaybeUninit::new(r),
+        }
+    }
+
+    pub const fn new_both(l: L, r: R) -> Self {
+        CAny {
+            flags: Self::LEFT | Self::RIGHT,
+            left: MaybeUninit::new(l),
+            right: MaybeUninit::new(r),
+        }
+    }
+
+    pub const fn new_neither() -> Self {
+        CAny {
+            flags: 0,
+            left: MaybeUninit::uninit(),
+            right: MaybeUninit::uninit(),
+        }
+    }
+
+    /// Moves a `CAny` out of `ptr`, checking its flags first. Fails with the
+    /// raw flags if any bit other than `LEFT` and `RIGHT` is set, in which
+    /// case nothing is read or moved.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and properly aligned. If the flags are
+    /// valid, the slot of every side they mark as present must be
+    /// initialized, and the value is moved out: the caller must not use or
+    /// drop it through `ptr` again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use anylr::ffi::CAny;
+    ///
+    /// let c = CAny::<u32, u64>::new_right(7);
+    /// let a = unsafe { CAny::try_from_raw(&c) }.unwrap();
+    /// core::mem::forget(c);
+    /// assert_eq!(a.right(), Some(&7));
+    ///
+    /// let mut bad = MaybeUninit::<CAny<u32, u64>>::zeroed();
+    /// unsafe { bad.as_mut_ptr().cast::<u8>().write(0b101) };
+    /// assert_eq!(unsafe { CAny::try_from_raw(bad.as_ptr()) }.err(), Some(0b101));
+    /// ```
+    pub unsafe fn try_from_raw(ptr: *const Self) -> Result<Self, u8> {
+        // SAFETY: The caller guarantees `ptr` is valid for reads and aligned,
+        // and the flags are the first byte of the `repr(C)` struct.
+        let flags = unsafe { ptr.cast::<u8>().read() };
+        if flags & !(Self::LEFT | Self::RIGHT) != 0 {
+            return Result::Err(flags);
+        }
+        // SAFETY: The flags are valid, so the caller guarantees the slots
+        // they mark are initialized and hands over ownership.
+        Result::Ok(unsafe { ptr.read() })
+    }
+
+    pub const fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns `None` if the flags are not valid.
+    pub const fn shape(&self) -> Option<Shape> {
+        Shape::from_bits(self.flags)
+    }
+
+    pub const fn contains_left(&self) -> bool {
+        self.flags & Self::LEFT != 0
+    }
+
+    pub const fn contains_right(&self) -> bool {
+        self.flags & Self::RIGHT != 0
+    }
+
+    pub fn left(&self) -> Option<&L> {
+        if self.contains_left() {
+            // SAFETY: The left slot is initialized when its flag is set.
+            Option::Some(unsafe { self.left.assume_init_ref() })
+        } else {
+            Option::None
+        }
+    }
+
+    pub fn right(&self) -> Option<&R> {
+        if self.contains_right() {
+            // SAFETY: The right slot is initialized when its flag is set.
+            Option::Some(unsafe { self.right.assume_init_ref() })
+        } else {
+            Option::None
+        }
+    }
+
+    /// Borrows the present values as an `Any<&L, &R>`, without moving them.
+    pub fn as_any(&self) -> Any<&L, &R> {
+        Any::from((self.left(), self.right()))
+    }
+
+    /// Borrows the present values as an `Any<&mut L, &mut R>`, without moving
+    /// them.
+    pub fn as_any_mut(&mut self) -> Any<&mut L, &mut R> {
+        let (has_l, has_r) = (self.contains_left(), self.contains_right());
+        let l = if has_l {
+            // SAFETY: The left slot is initialized when its flag is set.
+            Option::Some(unsafe { self.left.assume_init_mut() })
+        } else {
+            Option::None
+        };
+        let r = if has_r {
+            // SAFETY: The right slot is initialized when its flag is set.
+            Option::Some(unsafe { self.right.assume_init_mut() })
+        } else {
+            Option::None
+        };
+        Any::from((l, r))
+    }
+
+    fn into_split(self) -> (Option<L>, Option<R>) {
+        let value = ManuallyDrop::new(self);
+        // SAFETY: Each slot is read at most once, only if its flag is set, and
+        // `value` will not be dropped.
+        unsafe {
+            let l = if value.contains_left() {
+                Option::Some(value.left.assume_init_read())
+            } else {
+                Option::None
+            };
+            let r = if value.contains_right() {
+                Option::Some(value.right.assume_init_read())
+            } else {
+                Option::None
+            };
+            (l, r)
+        }
+    }
+}
+
+impl<L, R> Drop for CAny<L, R> {
+    fn drop(&mut self) {
+        // SAFETY: Each slot is dropped only if its flag is set, and it is never
+        // accessed again.
+        unsafe {
+            if self.contains_left() {
+                self.left.assume_init_drop();
+            }
+            if self.contains_right() {
+                self.right.assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<L: Clone, R: Clone> Clone for CAny<L, R> {
+    fn clone(&self) -> Self {
+        self.as_any().map_left(L::clone).map_right(R::clone).into()
+    }
+}
+
+impl<L: fmt::Debug, R: fmt::Debug> fmt::Debug for CAny<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CAny").field(&self.as_any()).finish()
+    }
+}
+
+impl<L, R> Default for CAny<L, R> {
+    fn default() -> Self {
+        CAny::new_neither()
+    }
+}
+
+impl<L, R> From<Any<L, R>> for CAny<L, R> {
+    fn from(value: Any<L, R>) -> Self {
+        match value.into_inner() {
+            AnyLR::Neither => CAny::new_neither(),
+            AnyLR::Left(l) => CAny::new_left(l),
+            AnyLR::Right(r) => CAny::new_right(r),
+            AnyLR::Both((l, r,)) => CAny::new_both(l, r),
+        }
+    }
+}
+
+impl<L, R> From<CAny<L, R>> for Any<L, R> {
+    fn from(value: CAny<L, R>) -> Self {
+        Any::from(value.into_split())
+    }
+}
+
+impl<L, R> From<SomeOf<L, R>> for CAny<L, R> {
+    fn from(value: SomeOf<L, R>) -> Self {
+        CAny::from(Any::from(value))
+    }
+}
+
+impl<L, R> TryFrom<CAny<L, R>> for SomeOf<L, R> {
+    type Error = CAny<L, R>;
+
+    /// Fails with the original value if it contains neither side.
+    fn try_from(value: CAny<L, R>) -> Result<Self, Self::Error> {
+        if value.flags & (CAny::<L, R>::LEFT | CAny::<L, R>::RIGHT) == 0 {
+            return Result::Err(value);
+        }
+        match value.into_split() {
+            (Option::Some(l), Option::Some(r)) => Result::Ok(SomeOf::new_both(l, r)),
+            (Option::Some(l), Option::None) => Result::Ok(SomeOf::new_left(l)),
+            (Option::None, Option::Some(r)) => Result::Ok(SomeOf::new_right(r)),
+            (Option::None, Option::None) => unreachable!(),
+        }
+    }
+}
+
+const _: () = {
+    use core::mem::{align_of, offset_of, size_of};
+
+    assert!(offset_of!(CEither<u8, u64>, tag) == 0);
+    assert!(offset_of!(CEither<u8, u64>, payload) == 8);
+    assert!(size_of::<CEither<u8, u64>>() == 16);
+    assert!(align_of::<CEither<u8, u64>>() == align_of::<u64>());
+    assert!(offset_of!(CEither<u8, u16>, payload) == 2);
+
+    assert!(offset_of!(CAny<u8, u64>, flags) == 0);
+    assert!(offset_of!(CAny<u8, u64>, left) == 1);
+    assert!(offset_of!(CAny<u8, u64>, right) == 8);
+    assert!(size_of::<CAny<u8, u64>>() == 16);
+    assert!(offset_of!(CAny<u32, u16>, left) == 4);
+    assert!(offset_of!(CAny<u32, u16>, right) == 8);
+    assert!(size_of::<CAny<u32, u16>>() == 12);
+};
@@ -0,0 +1,330 @@
+//! Heterogeneous lists built from nested pairs, the dual of `coprod`.
+//!
+//! `HList!(A, B, C)` is `(A, (B, (C, ())))`, and `hlist![a, b, c]` builds a
+//! value of it. Values are found by type, with the position inferred through
+//! the same `Here` and `There` indices as the coproducts use.
+//!
+//! # Examples
+//!
+//! ```
+//! use anylr::{hlist, hlist::TrHList, HList};
+//!
+//! let h: HList!(u8, &str, bool) = hlist![1, "two", true];
+//! assert_eq!(h.get::<&str, _>(), &"two");
+//!
+//! let (flag, rest) = h.pluck::<bool, _>();
+//! assert!(flag);
+//! assert_eq!(rest, hlist![1, "two"]);
+//! assert_eq!(rest.reversed().into_tuple(), ("two", 1));
+//! ```
+
+use crate::coprod::{Here, There};
+
+/// Expands to the nested pairs for a list of types, ending with `()`.
+#[macro_export]
+macro_rules! HList {
+    () => { () };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        ($head, $crate::HList!($($tail),*))
+    };
+}
+
+/// Builds a list from values, as nested pairs ending with `()`.
+///
+/// It also builds the handlers for `TrCoproduct::fold`.
+#[macro_export]
+macro_rules! hlist {
+    () => { () };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        ($head, $crate::hlist!($($tail),*))
+    };
+}
+
+macro_rules! hlist_pat {
+    () => { () };
+    ($head:ident $(, $tail:ident)*) => {
+        ($head, hlist_pat!($($tail),*))
+    };
+}
+
+/// Borrows the element of type `T` found at index `I`.
+pub trait TrHListGet<T, I> {
+    fn get(&self) -> &T;
+
+    fn get_mut(&mut self) -> &mut T;
+}
+
+impl<T, Tail> TrHListGet<T, Here> for (T, Tail) {
+    fn get(&self) -> &T {
+        &self.0
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<H, T, Tail, I> TrHListGet<T, There<I>> for (H, Tail)
+where
+    Tail: TrHListGet<T, I>,
+{
+    fn get(&self) -> &T {
+        self.1.get()
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self.1.get_mut()
+    }
+}
+
+/// Removes the element of type `T` found at index `I`, returning it together
+/// with the list of the other elements.
+pub trait TrHListPluck<T, I> {
+    type Remainder;
+
+    fn pluck(self) -> (T, Self::Remainder);
+}
+
+impl<T, Tail> TrHListPluck<T, Here> for (T, Tail) {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (T, Tail) {
+        self
+    }
+}
+
+impl<H, T, Tail, I> TrHListPluck<T, There<I>> for (H, Tail)
+where
+    Tail: TrHListPluck<T, I>,
+{
+    type Remainder = (H, Tail::Remainder);
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        let (h, tail) = self;
+        let (t, rest) = tail.pluck();
+        (t, (h, rest))
+    }
+}
+
+/// A function that accepts more than one argument type, for `TrHList::map`.
+pub trait TrPolyFn<T> {
+    type Output;
+
+    fn call(&mut self, value: T) -> Self::Output;
+}
+
+/// Applies a `TrPolyFn` to every element.
+pub trait TrHListMap<F> {
+    type Output;
+
+    fn map(self, f: &mut F) -> Self::Output;
+}
+
+impl<F> TrHListMap<F> for () {
+    type Output = ();
+
+    fn map(self, _: &mut F) {}
+}
+
+impl<F, H, Tail> TrHListMap<F> for (H, Tail)
+where
+    F: TrPolyFn<H>,
+    Tail: TrHListMap<F>,
+{
+    type Output = (F::Output, Tail::Output);
+
+    fn map(self, f: &mut F) -> Self::Output {
+        let (h, tail) = self;
+        let h = f.call(h);
+        (h, tail.map(f))
+    }
+}
+
+/// Moves the elements in front of `Acc`, last element first.
+pub trait TrHListReverseOnto<Acc> {
+    type Output;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Output;
+}
+
+impl<Acc> TrHListReverseOnto<Acc> for () {
+    type Output = Acc;
+
+    fn reverse_onto(self, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<Acc, H, Tail> TrHListReverseOnto<Acc> for (H, Tail)
+where
+    Tail: TrHListReverseOnto<(H, Acc)>,
+{
+    type Output = Tail::Output;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Output {
+        let (h, tail) = self;
+        tail.reverse_onto((h, acc))
+    }
+}
+
+/// Converts a flat tuple of up to 12 elements into a list and back.
+pub trait TrIntoHList {
+    type HList: TrHList;
+
+    fn into_hlist(self) -> Self::HList;
+
+    fn from_hlist(hlist: Self::HList) -> Self;
+}
+
+/// Converts a list of up to 12 elements into a flat tuple.
+pub trait TrIntoTuple {
+    type Tuple;
+
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+/// A list made of nested pairs ending with `()`.
+///
+/// `reversed` reverses the whole list, while `TrReverseLeftRight::reverse`
+/// only swaps the two halves of the outermost pair. It cannot be built on
+/// `TrReverseLeftRight`: `reverse` returns an opaque `impl
+/// TrReverseLeftRight`, so the swapped pair can be neither taken apart to
+/// recurse into nor named as an output type. Swapping every pair would not
+/// give a list anyway, as `(A, (B, ()))` would become `(((), B), A)`. Hence
+/// the accumulator in `TrHListReverseOnto`, and a name of its own, as a
+/// method called `reverse` would be ambiguous on every list with both traits
+/// in scope:
+///
+/// ```
+/// use anylr::{abs::TrReverseLeftRight, hlist, hlist::TrHList};
+///
+/// let h = hlist![1u8, 'b', "c"];
+/// assert_eq!(h.reversed(), hlist!["c", 'b', 1]);
+/// // Only the head and the tail trade places.
+/// let _swapped = h.reverse();
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use anylr::{hlist, hlist::{TrHList, TrIntoHList, TrPolyFn}};
+///
+/// struct Describe;
+///
+/// impl TrPolyFn<u8> for Describe {
+///     type Output = String;
+///
+///     fn call(&mut self, value: u8) -> String {
+///         format!("byte {value}")
+///     }
+/// }
+///
+/// impl TrPolyFn<&str> for Describe {
+///     type Output = usize;
+///
+///     fn call(&mut self, value: &str) -> usize {
+///         value.len()
+///     }
+/// }
+///
+/// let h = (7u8, "four").into_hlist();
+/// assert_eq!(h.map(Describe), hlist!["byte 7".to_owned(), 4]);
+/// assert_eq!(<(u8, &str)>::from_hlist(h), (7, "four"));
+/// ```
+pub trait TrHList: Sized {
+    const LEN: usize;
+
+    fn get<T, I>(&self) -> &T
+    where
+        Self: TrHListGet<T, I>,
+    {
+        TrHListGet::get(self)
+    }
+
+    fn get_mut<T, I>(&mut self) -> &mut T
+    where
+        Self: TrHListGet<T, I>,
+    {
+        TrHListGet::get_mut(self)
+    }
+
+    fn pluck<T, I>(self) -> (T, <Self as TrHListPluck<T, I>>::Remainder)
+    where
+        Self: TrHListPluck<T, I>,
+    {
+        TrHListPluck::pluck(self)
+    }
+
+    fn map<F>(self, mut f: F) -> <Self as TrHListMap<F>>::Output
+    where
+        Self: TrHListMap<F>,
+    {
+        TrHListMap::map(self, &mut f)
+    }
+
+    fn reversed(self) -> <Self as TrHListReverseOnto<()>>::Output
+    where
+        Self: TrHListReverseOnto<()>,
+    {
+        self.reverse_onto(())
+    }
+
+    fn into_tuple(self) -> <Self as TrIntoTuple>::Tuple
+    where
+        Self: TrIntoTuple,
+    {
+        TrIntoTuple::into_tuple(self)
+    }
+}
+
+impl TrHList for () {
+    const LEN: usize = 0;
+}
+
+impl<H, Tail: TrHList> TrHList for (H, Tail) {
+    const LEN: usize = 1 + Tail::LEN;
+}
+
+macro_rules! impl_tuple {
+    ($($t:ident),*) => {
+        impl<$($t),*> TrIntoHList for ($($t,)*) {
+            type HList = crate::HList!($($t),*);
+
+            #[allow(non_snake_case)]
+            fn into_hlist(self) -> Self::HList {
+                let ($($t,)*) = self;
+                crate::hlist!($($t),*)
+            }
+
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn from_hlist(hlist: Self::HList) -> Self {
+                let hlist_pat!($($t),*) = hlist;
+                ($($t,)*)
+            }
+        }
+
+        impl<$($t),*> TrIntoTuple for crate::HList!($($t),*) {
+            type Tuple = ($($t,)*);
+
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn into_tuple(self) -> Self::Tuple {
+                let hlist_pat!($($t),*) = self;
+                ($($t,)*)
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,130 @@
+//! Fan-out writers: `io::Write` for `Any` and `SomeOf`, writing every buffer
+//! to every present side. `fmt::Write` is implemented next to each type.
+//!
+//! The pair `(L, R)` cannot implement either trait, as the orphan rule forbids
+//! implementing a foreign trait for a foreign type. Convert the pair with
+//! `SomeOf::from(pair)` to write to both values.
+
+use std::io;
+
+use crate::{Any, SomeOf};
+
+/// Folds the failures of a fan-out write into one `io::Error`. The `SomeOf`
+/// can be recovered with `io::Error::into_inner` and `downcast`.
+///
+/// The kind of the left failure, or else the right one, is kept only if every
+/// present side failed. If a side succeeded, it already holds the buffer, so
+/// the kind is `ErrorKind::Other`: a retryable kind such as `WouldBlock` or
+/// `Interrupted` would make callers write the buffer to it twice.
+fn tee_error(errors: SomeOf<io::Error, io::Error>, both_present: bool) -> io::Error {
+    let kind = match errors.as_ref().split() {
+        (Option::Some(_), Option::None) | (Option::None, Option::Some(_)) if both_present => {
+            io::ErrorKind::Other
+        }
+        (Option::Some(l), _) => l.kind(),
+        (Option::None, Option::Some(r)) => r.kind(),
+        (Option::None, Option::None) => unreachable!(),
+    };
+    io::Error::new(kind, errors)
+}
+
+/// Writes every buffer in full to every present side, so `write` accepts the
+/// whole buffer or fails. A failure on one side does not stop the other side
+/// from being written; the error then has kind `ErrorKind::Other`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Write};
+/// use anylr::{Any, SomeOf};
+///
+/// let mut small = [0u8; 2];
+/// let mut tee = Any::new_both(Vec::new(), &mut small[..]);
+/// let e = tee.write_all(b"abc").unwrap_err();
+/// assert_eq!(e.kind(), io::ErrorKind::Other);
+///
+/// let e = e.into_inner().unwrap().downcast::<SomeOf<io::Error, io::Error>>().unwrap();
+/// assert!(e.contains_right() && !e.contains_left());
+/// assert_eq!(e.unwrap_right().kind(), io::ErrorKind::WriteZero);
+/// assert_eq!(tee.unwrap_left(), b"abc");
+/// ```
+impl<L: io::Write, R: io::Write> io::Write for Any<L, R> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf).map(|()| buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let both = self.is_both();
+        self.try_each_mut(|l| l.write_all(buf), |r| r.write_all(buf))
+            .map_err(|e| tee_error(e, both))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let both = self.is_both();
+        self.try_each_mut(|l| l.flush(), |r| r.flush())
+            .map_err(|e| tee_error(e, both))
+    }
+}
+
+/// Writes every buffer in full to every present side, like the impl for `Any`.
+impl<L: io::Write, R: io::Write> io::Write for SomeOf<L, R> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf).map(|()| buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let both = self.is_both();
+        self.try_each_mut(|l| l.write_all(buf), |r| r.write_all(buf))
+            .map_err(|e| tee_error(e, both))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let both = self.is_both();
+        self.try_each_mut(|l| l.flush(), |r| r.flush())
+            .map_err(|e| tee_error(e, both))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+    use std::vec::Vec;
+
+    use crate::{Any, SomeOf};
+
+    struct Refuse(io::ErrorKind);
+
+    impl io::Write for Refuse {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Result::Err(self.0.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Result::Ok(())
+        }
+    }
+
+    #[test]
+    fn half_failed_write_is_not_retryable() {
+        let mut tee = Any::new_both(Vec::new(), Refuse(io::ErrorKind::WouldBlock));
+        let e = tee.write(b"abc").unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::Other);
+        assert_eq!(tee.unwrap_left(), b"abc");
+
+        let mut tee = SomeOf::new_both(Refuse(io::ErrorKind::TimedOut), Vec::new());
+        assert_eq!(tee.write(b"abc").unwrap_err().kind(), io::ErrorKind::Other);
+        assert_eq!(tee.unwrap_right(), b"abc");
+    }
+
+    #[test]
+    fn fully_failed_write_keeps_the_kind() {
+        let mut tee = Any::<Vec<u8>, _>::new_right(Refuse(io::ErrorKind::WouldBlock));
+        assert_eq!(tee.write(b"abc").unwrap_err().kind(), io::ErrorKind::WouldBlock);
+
+        let mut tee = SomeOf::new_both(
+            Refuse(io::ErrorKind::WouldBlock),
+            Refuse(io::ErrorKind::Other),
+        );
+        assert_eq!(tee.write(b"abc").unwrap_err().kind(), io::ErrorKind::WouldBlock);
+    }
+}
@@ -1,11 +1,93 @@
+//! Provides `Any<L, R>`, `SomeOf<L, R>` and `Either<L, R>`, along with the
+//! traits in [`abs`] that they share with `Result`, `Option` and the pair
+//! `(L, R)`.
+//!
+//! # Conversions
+//!
+//! Widening conversions, which never lose a value, are `From`. Narrowing
+//! conversions are `TryFrom`, and give the original value back as the error
+//! when the shape does not fit.
+//!
+//! | from \ into         | `Either`   | `SomeOf`  | `Any`  | `(L, R)`  | `Result`  | `Option<L>` | `(Option, Option)` |
+//! |---------------------|------------|-----------|--------|-----------|-----------|-------------|--------------------|
+//! | `Either`            |            | `From`    | `From` | [^never]  | `From`    | `From`[^1]  | `From`             |
+//! | `SomeOf`            | `TryFrom`  |           | `From` | `TryFrom` | `TryFrom` | `TryFrom`   | `From`             |
+//! | `Any`               | `TryFrom`  | `TryFrom` |        | `TryFrom` | `TryFrom` | `TryFrom`   | `From`             |
+//! | `(L, R)`            | [^never]   | `From`    | `From` |           | [^never]  | [^orphan]   | [^orphan]          |
+//! | `Result`            | `From`     | `From`    | `From` | [^never]  |           | [^orphan]   | [^orphan]          |
+//! | `Option<L>`         | `From`[^1] | `TryFrom` | `From` | [^orphan] | [^orphan] |             | [^orphan]          |
+//! | `(Option, Option)`  | `TryFrom`  | `TryFrom` | `From` | [^orphan] | [^orphan] | [^orphan]   |                    |
+//!
+//! [^1]: Between `Either<L, ()>` and `Option<L>`, with `None` as `Right(())`.
+//!
+//! [^never]: Left out on purpose: an `Either` or a `Result` never holds both
+//! values and a pair always does, so no value of one type could ever be
+//! converted into the other.
+//!
+//! [^orphan]: Not possible, as neither type is defined in this crate. Go
+//! through `Any`, which converts from all of them, e.g.
+//! `<(L, R)>::try_from(Any::from(pair_of_options))`.
+//!
+//! Besides, `Any<L, R>` is isomorphic to `Option<SomeOf<L, R>>`, with
+//! `Neither` as `None`, and both directions are `From`.
+//!
+//! # Examples
+//!
+//! ```
+//! use anylr::{Any, Either, SomeOf};
+//!
+//! let s = SomeOf::new_both(1u8, 'a');
+//! let s = Either::try_from(s).unwrap_err();
+//! let a = Any::from(s);
+//! let a = SomeOf::try_from(a).map(Any::from).unwrap();
+//! assert_eq!(<(u8, char)>::try_from(a).ok(), Some((1, 'a')));
+//!
+//! let r: Result<u8, char> = Either::Left(1).into();
+//! assert_eq!(r, Ok(1));
+//!
+//! assert_eq!(Option::<u8>::try_from(Any::<u8, char>::new_neither()).ok(), Some(None));
+//! assert!(Option::<u8>::try_from(SomeOf::<u8, char>::new_right('a')).is_err());
+//! assert_eq!(Option::<u8>::from(Either::Right(())), None);
+//!
+//! let pair = <(u8, char)>::try_from(Any::from((Some(1), Some('a')))).ok();
+//! assert_eq!(pair, Some((1, 'a')));
+//! ```
+
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod abs;
 pub mod any_of;
 pub mod both;
+pub mod coprod;
 pub mod either;
+pub mod ffi;
+pub mod hlist;
+pub mod shape;
 pub mod some_of;
+pub mod static_any;
+
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "std")]
+mod io_impl;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+
+#[cfg(feature = "macros")]
+pub use anylr_macros::delegate;
 
 pub use any_of::Any;
 pub use either::Either;
+pub use shape::Shape;
 pub use some_of::SomeOf;
+pub use static_any::StaticAny;
@@ -0,0 +1,169 @@
+//! `rayon` support: `Either` of two parallel iterators is a parallel iterator,
+//! and `TrParallelPartition` splits left and right values concurrently.
+
+use rayon::iter::{
+    plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    IndexedParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+};
+
+use crate::{abs::TrAnyLeftRight, Either};
+
+/// Runs whichever parallel iterator is present.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::Either;
+/// use rayon::prelude::*;
+///
+/// let v = vec![1, 2, 3];
+/// let it = if v.len() > 2 {
+///     Either::Left(v.par_iter().map(|x| x * 2))
+/// } else {
+///     Either::Right(v.par_iter().copied())
+/// };
+/// assert_eq!(it.sum::<i32>(), 12);
+/// ```
+impl<L, R> ParallelIterator for Either<L, R>
+where
+    L: ParallelIterator,
+    R: ParallelIterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        match self {
+            Either::Left(l) => l.drive_unindexed(consumer),
+            Either::Right(r) => r.drive_unindexed(consumer),
+        }
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        match self {
+            Either::Left(l) => l.opt_len(),
+            Either::Right(r) => r.opt_len(),
+        }
+    }
+}
+
+impl<L, R> IndexedParallelIterator for Either<L, R>
+where
+    L: IndexedParallelIterator,
+    R: IndexedParallelIterator<Item = L::Item>,
+{
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        match self {
+            Either::Left(l) => l.drive(consumer),
+            Either::Right(r) => r.drive(consumer),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Either::Left(l) => l.len(),
+            Either::Right(r) => r.len(),
+        }
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        match self {
+            Either::Left(l) => l.with_producer(callback),
+            Either::Right(r) => r.with_producer(callback),
+        }
+    }
+}
+
+/// Extends a collection with the present values of a parallel iterator of
+/// `Option`s, so that `unzip` can fill both sides at once.
+#[derive(Default)]
+struct SomeSink<C>(C);
+
+impl<T: Send, C: ParallelExtend<T>> ParallelExtend<Option<T>> for SomeSink<C> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = Option<T>>,
+    {
+        self.0.par_extend(par_iter.into_par_iter().flatten())
+    }
+}
+
+/// Parallel counterpart of `TrPartition`, which splits an iterator of
+/// left-right values into the left values and the right values.
+pub trait TrParallelPartition: ParallelIterator {
+    /// Collects every left value into `A` and every right value into `B`,
+    /// filling both collections concurrently. Works for items of any
+    /// `TrAnyLeftRight` type, so a `SomeOf` or an `Any` can feed both sides
+    /// or neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{par::TrParallelPartition, Any, Either};
+    /// use rayon::prelude::*;
+    ///
+    /// let (evens, odds): (Vec<u32>, Vec<u32>) = (0..6u32)
+    ///     .into_par_iter()
+    ///     .map(|x| if x % 2 == 0 { Either::Left(x) } else { Either::Right(x) })
+    ///     .partition_left_right();
+    /// assert_eq!((evens, odds), (vec![0, 2, 4], vec![1, 3, 5]));
+    ///
+    /// let (small, chars): (Vec<u8>, String) = vec![
+    ///     Any::new_both(1u8, 'a'),
+    ///     Any::new_neither(),
+    ///     Any::new_right('b'),
+    /// ]
+    /// .into_par_iter()
+    /// .partition_left_right();
+    /// assert_eq!((small, chars.as_str()), (vec![1], "ab"));
+    /// ```
+    fn partition_left_right<A, B>(self) -> (A, B)
+    where
+        Self::Item: TrAnyLeftRight,
+        <Self::Item as TrAnyLeftRight>::Lt: Send,
+        <Self::Item as TrAnyLeftRight>::Rt: Send,
+        A: Default + Send + ParallelExtend<<Self::Item as TrAnyLeftRight>::Lt>,
+        B: Default + Send + ParallelExtend<<Self::Item as TrAnyLeftRight>::Rt>,
+    {
+        let (SomeSink(a), SomeSink(b)) = self.map(TrAnyLeftRight::split).unzip();
+        (a, b)
+    }
+}
+
+impl<I: ParallelIterator> TrParallelPartition for I {}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use rayon::prelude::*;
+
+    use super::TrParallelPartition;
+    use crate::{abs::TrPartition, Any};
+
+    #[test]
+    fn parallel_partition_matches_sequential() {
+        let items: Vec<Any<u32, i64>> = (0..1000u32)
+            .map(|x| match x % 4 {
+                0 => Any::new_neither(),
+                1 => Any::new_left(x),
+                2 => Any::new_right(-i64::from(x)),
+                _ => Any::new_both(x, i64::from(x)),
+            })
+            .collect();
+        let par: (Vec<u32>, Vec<i64>) = items.clone().into_par_iter().partition_left_right();
+        let seq: (Vec<u32>, Vec<i64>) = items.into_iter().partition_left_right();
+        assert_eq!(par, seq);
+        assert_eq!((par.0.len(), par.1.len()), (500, 500));
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::boxed::Box;
+use core::iter;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    any_of::AnyLR,
+    some_of::SomeLR,
+    Any, Either, SomeOf,
+};
+
+/// Shrinks towards simpler shapes first (`Both` to `Left` to `Neither`), and
+/// then shrinks the payloads while keeping the shape.
+impl<L, R> Arbitrary for Any<L, R>
+where
+    L: Arbitrary,
+    R: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        match g.choose(&[0u8, 1, 2, 3]) {
+            Option::Some(1) => Any::new_left(L::arbitrary(g)),
+            Option::Some(2) => Any::new_right(R::arbitrary(g)),
+            Option::Some(3) => Any::new_both(L::arbitrary(g), R::arbitrary(g)),
+            _ => Any::new_neither(),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self.clone().into_inner() {
+            AnyLR::Neither => quickcheck::empty_shrinker(),
+            AnyLR::Left(l) => Box::new(
+                iter::once(Any::new_neither())
+                    .chain(l.shrink().map(Any::new_left)),
+            ),
+            AnyLR::Right(r) => Box::new(
+                iter::once(Any::new_neither())
+                    .chain(r.shrink().map(Any::new_right)),
+            ),
+            AnyLR::Both((l, r,)) => {
+                let r_ = r.clone();
+                Box::new(
+                    iter::once(Any::new_left(l.clone()))
+                        .chain(iter::once(Any::new_right(r.clone())))
+                        .chain(l.shrink().map(move |l| Any::new_both(l, r_.clone())))
+                        .chain(r.shrink().map(move |r| Any::new_both(l.clone(), r))),
+                )
+            }
+        }
+    }
+}
+
+/// Shrinks `Both` to `Left` and `Right` first, and then shrinks the payloads
+/// while keeping the shape.
+impl<L, R> Arbitrary for SomeOf<L, R>
+where
+    L: Arbitrary,
+    R: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        match g.choose(&[0u8, 1, 2]) {
+            Option::Some(1) => SomeOf::new_right(R::arbitrary(g)),
+            Option::Some(2) => SomeOf::new_both(L::arbitrary(g), R::arbitrary(g)),
+            _ => SomeOf::new_left(L::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self.clone().into_inner() {
+            SomeLR::Both((l, r,)) => {
+                let r_ = r.clone();
+                Box::new(
+                    iter::once(SomeOf::new_left(l.clone()))
+                        .chain(iter::once(SomeOf::new_right(r.clone())))
+                        .chain(l.shrink().map(move |l| SomeOf::new_both(l, r_.clone())))
+                        .chain(r.shrink().map(move |r| SomeOf::new_both(l.clone(), r))),
+                )
+            }
+            SomeLR::Left(l) => Box::new(l.shrink().map(SomeOf::new_left)),
+            SomeLR::Right(r) => Box::new(r.shrink().map(SomeOf::new_right)),
+        }
+    }
+}
+
+impl<L, R> Arbitrary for Either<L, R>
+where
+    L: Arbitrary,
+    R: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Either::new_left(L::arbitrary(g))
+        } else {
+            Either::new_right(R::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Either::Left(l) => Box::new(l.shrink().map(Either::new_left)),
+            Either::Right(r) => Box::new(r.shrink().map(Either::new_right)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use quickcheck::{Arbitrary, Gen};
+
+    use crate::{Any, Either, Shape, SomeOf};
+
+    #[test]
+    fn any_shrinks_both_to_one_side_first() {
+        let shrunk: Vec<_> = Any::new_both(3u8, 'x').shrink().take(2).collect();
+        assert_eq!(shrunk[0].shape(), Shape::Left);
+        assert_eq!(shrunk[1].shape(), Shape::Right);
+        assert!(Any::<u8, char>::new_left(3).shrink().next().unwrap().is_neither());
+    }
+
+    #[test]
+    fn some_of_shrinks_both_to_one_side_first() {
+        let shrunk: Vec<_> = SomeOf::new_both(3u8, 'x').shrink().take(2).collect();
+        assert_eq!(shrunk[0].shape(), Shape::Left);
+        assert_eq!(shrunk[1].shape(), Shape::Right);
+    }
+
+    #[test]
+    fn arbitrary_generates_every_shape() {
+        let mut g = Gen::new(8);
+        let mut any_shapes = [false; 4];
+        let mut some_shapes = [false; 4];
+        let mut either_shapes = [false; 4];
+        for _ in 0..256 {
+            any_shapes[Any::<u8, u8>::arbitrary(&mut g).shape().bits() as usize] = true;
+            some_shapes[SomeOf::<u8, u8>::arbitrary(&mut g).shape().bits() as usize] = true;
+            either_shapes[Either::<u8, u8>::arbitrary(&mut g).shape().bits() as usize] = true;
+        }
+        assert_eq!(any_shapes, [true; 4]);
+        assert_eq!(some_shapes, [false, true, true, true]);
+        assert_eq!(either_shapes, [false, true, true, false]);
+    }
+}
@@ -0,0 +1,153 @@
+use core::ops::{BitAnd, BitOr};
+
+/// Tells which sides are present in a left-right value, without borrowing or
+/// consuming the values themselves.
+///
+/// The discriminant is a bit set, with bit 0 for the left side and bit 1 for
+/// the right side.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::{Any, Either, Shape, SomeOf};
+///
+/// assert_eq!(Any::<u8, u8>::new_neither().shape(), Shape::Neither);
+/// assert_eq!(Either::<u8, u8>::Right(1).shape(), Shape::Right);
+/// assert_eq!(SomeOf::new_both(1u8, 2u8).shape(), Shape::Both);
+///
+/// assert_eq!(Shape::Left.union(Shape::Right), Shape::Both);
+/// assert_eq!(Shape::Both.intersection(Shape::Right), Shape::Right);
+/// assert_eq!(Shape::from_bits(0b01), Some(Shape::Left));
+/// assert_eq!(Shape::from_bits(0b100), None);
+/// ```
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shape {
+    Neither = 0b00,
+    Left = 0b01,
+    Right = 0b10,
+    Both = 0b11,
+}
+
+impl Shape {
+    /// Creates a shape from whether the left and the right values are present.
+    pub const fn new(has_left: bool, has_right: bool) -> Self {
+        match (has_left, has_right) {
+            (false, false) => Shape::Neither,
+            (true, false) => Shape::Left,
+            (false, true) => Shape::Right,
+            (true, true) => Shape::Both,
+        }
+    }
+
+    /// Returns `None` if any bit other than the lowest two is set.
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0b00 => Option::Some(Shape::Neither),
+            0b01 => Option::Some(Shape::Left),
+            0b10 => Option::Some(Shape::Right),
+            0b11 => Option::Some(Shape::Both),
+            _ => Option::None,
+        }
+    }
+
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+
+    pub const fn has_left(self) -> bool {
+        self.bits() & Shape::Left.bits() != 0
+    }
+
+    pub const fn has_right(self) -> bool {
+        self.bits() & Shape::Right.bits() != 0
+    }
+
+    /// The sides present in either `self` or `other`.
+    pub const fn union(self, other: Shape) -> Shape {
+        Shape::new(
+            self.has_left() || other.has_left(),
+            self.has_right() || other.has_right(),
+        )
+    }
+
+    /// The sides present in both `self` and `other`.
+    pub const fn intersection(self, other: Shape) -> Shape {
+        Shape::new(
+            self.has_left() && other.has_left(),
+            self.has_right() && other.has_right(),
+        )
+    }
+
+    /// Swaps the left and the right sides.
+    pub const fn reverse(self) -> Shape {
+        Shape::new(self.has_right(), self.has_left())
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn unwrap_failed(method: &str, found: Shape) -> ! {
+    panic!("called `{method}` on a value of shape `{found:?}`")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn expect_failed(msg: &str, found: Shape) -> ! {
+    panic!("{msg}: found shape `{found:?}`")
+}
+
+impl BitOr for Shape {
+    type Output = Shape;
+
+    #[inline]
+    fn bitor(self, rhs: Shape) -> Shape {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for Shape {
+    type Output = Shape;
+
+    #[inline]
+    fn bitand(self, rhs: Shape) -> Shape {
+        self.intersection(rhs)
+    }
+}
+
+impl From<Shape> for u8 {
+    fn from(value: Shape) -> Self {
+        value.bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Any, Either, SomeOf};
+
+    #[test]
+    #[should_panic(expected = "called `Any::unwrap_left()` on a value of shape `Right`")]
+    fn any_unwrap_left_names_the_shape() {
+        Any::<u8, char>::new_right('a').unwrap_left();
+    }
+
+    #[test]
+    #[should_panic(expected = "called `Either::unwrap_left()` on a value of shape `Right`")]
+    fn either_unwrap_left_names_the_shape() {
+        Either::<u8, char>::Right('a').unwrap_left();
+    }
+
+    #[test]
+    #[should_panic(expected = "called `SomeOf::unwrap_left()` on a value of shape `Right`")]
+    fn some_of_unwrap_left_names_the_shape() {
+        SomeOf::<u8, char>::new_right('a').unwrap_left();
+    }
+
+    #[test]
+    #[should_panic(expected = "not both: found shape `Left`")]
+    fn expect_both_names_the_shape() {
+        SomeOf::<u8, char>::new_left(1).expect_both("not both");
+    }
+}
@@ -1,6 +1,20 @@
+use core::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    iter,
+    option,
+    mem,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    ptr,
+    str::FromStr,
+};
+
 use crate::{
     abs::{TrAnyLeftRight, TrReverseLeftRight},
-    Either, Any,
+    any_of::AnyLR,
+    shape, Either, Any, Shape,
 };
 
 /// An wrapper around `SomeLR<L, R>`. This is to avoid misunderstanding for
@@ -22,6 +36,37 @@ impl<L, R> SomeOf<L, R> {
         SomeOf(SomeLR::Both((l, r)))
     }
 
+    /// Parses `s` as both `L` and `R`, keeping every side that succeeds. Both
+    /// errors are returned if neither parses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let s = SomeOf::<u8, f32>::parse_all("7").unwrap();
+    /// assert_eq!(s.split(), (Some(7), Some(7.0)));
+    ///
+    /// let s = SomeOf::<u8, f32>::parse_all("0.5").unwrap();
+    /// assert_eq!(s.split(), (None, Some(0.5)));
+    ///
+    /// assert!(SomeOf::<u8, f32>::parse_all("x").unwrap_err().is_both());
+    /// ```
+    pub fn parse_all(s: &str) -> Result<Self, SomeOf<L::Err, R::Err>>
+    where
+        L: FromStr,
+        R: FromStr,
+    {
+        match (L::from_str(s), R::from_str(s)) {
+            (Result::Ok(l), Result::Ok(r)) => Result::Ok(SomeOf::new_both(l, r)),
+            (Result::Ok(l), Result::Err(_)) => Result::Ok(SomeOf::new_left(l)),
+            (Result::Err(_), Result::Ok(r)) => Result::Ok(SomeOf::new_right(r)),
+            (Result::Err(l_err), Result::Err(r_err)) => {
+                Result::Err(SomeOf::new_both(l_err, r_err))
+            }
+        }
+    }
+
     pub fn split(self) -> (Option<L>, Option<R>) {
         SomeLR::split(self.0)
     }
@@ -56,7 +101,7 @@ impl<L, R> SomeOf<L, R> {
         }
     }
 
-    pub fn as_ref(&self) -> SomeOf<&L, &R> {
+    pub const fn as_ref(&self) -> SomeOf<&L, &R> {
         match &self.0 {
             SomeLR::Left(l) => SomeOf::new_left(l),
             SomeLR::Right(r) => SomeOf::new_right(r),
@@ -64,7 +109,7 @@ impl<L, R> SomeOf<L, R> {
         }
     }
 
-    pub fn as_mut(&mut self) -> SomeOf<&mut L, &mut R> {
+    pub const fn as_mut(&mut self) -> SomeOf<&mut L, &mut R> {
         match &mut self.0 {
             SomeLR::Left(l) => SomeOf::new_left(l),
             SomeLR::Right(r) => SomeOf::new_right(r),
@@ -72,28 +117,960 @@ impl<L, R> SomeOf<L, R> {
         }
     }
 
+    /// Projects a pinned reference to pinned references of the contained
+    /// values.
+    pub fn as_pin_ref(self: Pin<&Self>) -> SomeOf<Pin<&L>, Pin<&R>> {
+        // SAFETY: See `as_pin_mut`.
+        unsafe {
+            match &self.get_ref().0 {
+                SomeLR::Left(l) => SomeOf::new_left(Pin::new_unchecked(l)),
+                SomeLR::Right(r) => SomeOf::new_right(Pin::new_unchecked(r)),
+                SomeLR::Both((l, r)) => SomeOf::new_both(Pin::new_unchecked(l), Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Projects a pinned mutable reference to pinned mutable references of
+    /// the contained values.
+    ///
+    /// The values are structurally pinned, see `Either::as_pin_mut` for why
+    /// this is sound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{marker::PhantomPinned, pin::pin};
+    /// use anylr::SomeOf;
+    ///
+    /// let mut s = pin!(SomeOf::new_both(PhantomPinned, 1u8));
+    /// let (l, r) = s.as_mut().as_pin_mut().split();
+    /// assert!(l.is_some());
+    /// *r.unwrap() += 1;
+    /// assert_eq!(s.as_ref().as_pin_ref().split().1.as_deref(), Some(&2));
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> SomeOf<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: The contained values are structurally pinned.
+        unsafe {
+            match &mut self.get_unchecked_mut().0 {
+                SomeLR::Left(l) => SomeOf::new_left(Pin::new_unchecked(l)),
+                SomeLR::Right(r) => SomeOf::new_right(Pin::new_unchecked(r)),
+                SomeLR::Both((l, r)) => SomeOf::new_both(Pin::new_unchecked(l), Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Calls `f` with a reference to the left value if present, and returns
+    /// `self` untouched.
+    pub fn inspect_left<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&L),
+    {
+        if let Option::Some(l) = self.as_ref().split().0 {
+            f(l)
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the right value if present, and returns
+    /// `self` untouched.
+    pub fn inspect_right<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&R),
+    {
+        if let Option::Some(r) = self.as_ref().split().1 {
+            f(r)
+        }
+        self
+    }
+
+    /// Calls `f` with references to both values if both are present, and
+    /// returns `self` untouched.
+    pub fn inspect_both<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&L, &R),
+    {
+        if let (Option::Some(l), Option::Some(r)) = self.as_ref().split() {
+            f(l, r)
+        }
+        self
+    }
+
+    /// Calls `f` on the left value and `g` on the right value, whichever are
+    /// present, even if the first call fails. See `Any::try_each_mut`.
+    pub fn try_each_mut<F, G, E1, E2>(&mut self, f: F, g: G) -> Result<(), SomeOf<E1, E2>>
+    where
+        F: FnOnce(&mut L) -> Result<(), E1>,
+        G: FnOnce(&mut R) -> Result<(), E2>,
+    {
+        let (l, r) = self.as_mut().split();
+        let l = l.map(f).and_then(Result::err);
+        let r = r.map(g).and_then(Result::err);
+        match SomeOf::try_from((l, r,)) {
+            Result::Ok(errors) => Result::Err(errors),
+            Result::Err(_) => Result::Ok(()),
+        }
+    }
+
+    /// Removes the left value unless it satisfies `pred`. The result is an
+    /// `Any<L, R>` since it may become empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Shape, SomeOf};
+    ///
+    /// let s = SomeOf::<u8, char>::new_left(1);
+    /// assert_eq!(s.filter_left(|l| *l > 1).shape(), Shape::Neither);
+    /// assert_eq!(s.filter_right(|_| false).shape(), Shape::Left);
+    /// ```
+    pub fn filter_left<P>(self, pred: P) -> Any<L, R>
+    where
+        P: FnOnce(&L) -> bool,
+    {
+        Any::from(self).filter_left(pred)
+    }
+
+    /// Removes the right value unless it satisfies `pred`. The result is an
+    /// `Any<L, R>` since it may become empty.
+    pub fn filter_right<P>(self, pred: P) -> Any<L, R>
+    where
+        P: FnOnce(&R) -> bool,
+    {
+        Any::from(self).filter_right(pred)
+    }
+
+    /// Maps the left value with `f`, removing it if `f` returns `None`.
+    pub fn filter_map_left<F, U>(self, f: F) -> Any<U, R>
+    where
+        F: FnOnce(L) -> Option<U>,
+    {
+        Any::from(self).filter_map_left(f)
+    }
+
+    /// Maps the right value with `f`, removing it if `f` returns `None`.
+    pub fn filter_map_right<F, U>(self, f: F) -> Any<L, U>
+    where
+        F: FnOnce(R) -> Option<U>,
+    {
+        Any::from(self).filter_map_right(f)
+    }
+
+    /// Pairs up the values of the same side, keeping only the sides present
+    /// in both `self` and `other`. The result is an `Any` since no side may be
+    /// present in both.
+    pub fn zip<L2, R2>(self, other: SomeOf<L2, R2>) -> Any<(L, L2), (R, R2)> {
+        Any::from(self).zip(Any::from(other))
+    }
+
+    /// Combines the values of the same side with `fl` and `fr`, keeping only
+    /// the sides present in both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let a = SomeOf::new_both(1u8, 2u8);
+    /// let b = SomeOf::<u8, u8>::new_right(3);
+    /// let z = a.zip_with(b, |x, y| x + y, |x, y| x * y);
+    /// assert_eq!(z.split(), (None, Some(6)));
+    /// ```
+    pub fn zip_with<L2, R2, FL, FR, U, V>(
+        self,
+        other: SomeOf<L2, R2>,
+        fl: FL,
+        fr: FR,
+    ) -> Any<U, V>
+    where
+        FL: FnOnce(L, L2) -> U,
+        FR: FnOnce(R, R2) -> V,
+    {
+        Any::from(self).zip_with(Any::from(other), fl, fr)
+    }
+
+    /// Converts from `&SomeOf<L, R>` to `SomeOf<&L::Target, &R::Target>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let s = SomeOf::<String, Box<u8>>::new_right(Box::new(1));
+    /// assert_eq!(s.as_deref().split(), (None, Some(&1)));
+    /// ```
+    pub fn as_deref(&self) -> SomeOf<&L::Target, &R::Target>
+    where
+        L: Deref,
+        R: Deref,
+    {
+        self.as_ref().map_left(Deref::deref).map_right(Deref::deref)
+    }
+
+    /// Converts from `&mut SomeOf<L, R>` to `SomeOf<&mut L::Target, &mut R::Target>`.
+    pub fn as_deref_mut(&mut self) -> SomeOf<&mut L::Target, &mut R::Target>
+    where
+        L: DerefMut,
+        R: DerefMut,
+    {
+        self.as_mut().map_left(DerefMut::deref_mut).map_right(DerefMut::deref_mut)
+    }
+
     pub fn reverse(self) -> SomeOf<R, L> {
         SomeOf(self.0.reverse())
     }
 
     /// The variant is `SomeOf::Left` or `SomeOf::Both`
-    pub fn is_left(&self) -> bool {
+    pub const fn is_left(&self) -> bool {
+        self.0.is_left()
+    }
+
+    /// The same as `is_left`
+    pub const fn contains_left(&self) -> bool {
         self.0.is_left()
     }
 
+    /// The same as `is_right`
+    pub const fn contains_right(&self) -> bool {
+        self.0.is_right()
+    }
+
     /// The variant is `SomeOf::Right` or `SomeOf::Both`
-    pub fn is_right(&self) -> bool {
+    pub const fn is_right(&self) -> bool {
         self.0.is_right()
     }
 
     /// The variant is just `SomeOf::Both`
-    pub fn is_both(&self) -> bool {
+    pub const fn is_both(&self) -> bool {
         self.0.is_both()
     }
 
+    pub const fn shape(&self) -> Shape {
+        self.0.shape()
+    }
+
     pub fn into_inner(self) -> SomeLR<L, R> {
         self.0
     }
+
+    /// Replaces the value with `value`, returning the old one.
+    #[inline]
+    pub fn replace(&mut self, value: Self) -> Self {
+        mem::replace(self, value)
+    }
+
+    /// Sets the left value, returning the previous one if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Shape, SomeOf};
+    ///
+    /// let mut s = SomeOf::<u8, char>::new_right('a');
+    /// assert_eq!(s.insert_left(1), None);
+    /// assert_eq!(s.insert_left(2), Some(1));
+    /// assert_eq!(s.shape(), Shape::Both);
+    /// ```
+    pub fn insert_left(&mut self, l: L) -> Option<L> {
+        match &mut self.0 {
+            SomeLR::Left(prev) | SomeLR::Both((prev, _,)) => Option::Some(mem::replace(prev, l)),
+            SomeLR::Right(_) => {
+                self.rebuild_with(|x| match x {
+                    SomeLR::Right(r) => (SomeLR::Both((l, r,)), ()),
+                    x => (x, ()),
+                });
+                Option::None
+            }
+        }
+    }
+
+    /// Sets the right value, returning the previous one if any.
+    pub fn insert_right(&mut self, r: R) -> Option<R> {
+        match &mut self.0 {
+            SomeLR::Right(prev) | SomeLR::Both((_, prev,)) => Option::Some(mem::replace(prev, r)),
+            SomeLR::Left(_) => {
+                self.rebuild_with(|x| match x {
+                    SomeLR::Left(l) => (SomeLR::Both((l, r,)), ()),
+                    x => (x, ()),
+                });
+                Option::None
+            }
+        }
+    }
+
+    /// Removes the left value in place, which only succeeds if the right value
+    /// is also present, since a `SomeOf<L, R>` can never become empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Shape, SomeOf};
+    ///
+    /// let mut s = SomeOf::new_both(1u8, 'a');
+    /// assert_eq!(s.take_left_in_place(), Some(1));
+    /// assert_eq!(s.shape(), Shape::Right);
+    /// assert_eq!(s.take_right_in_place(), None);
+    /// assert_eq!(s.shape(), Shape::Right);
+    /// ```
+    pub fn take_left_in_place(&mut self) -> Option<L> {
+        if !self.is_both() {
+            return Option::None;
+        }
+        self.rebuild_with(|x| match x {
+            SomeLR::Both((l, r,)) => (SomeLR::Right(r), Option::Some(l)),
+            x => (x, Option::None),
+        })
+    }
+
+    /// Removes the right value in place, which only succeeds if the left value
+    /// is also present, since a `SomeOf<L, R>` can never become empty.
+    pub fn take_right_in_place(&mut self) -> Option<R> {
+        if !self.is_both() {
+            return Option::None;
+        }
+        self.rebuild_with(|x| match x {
+            SomeLR::Both((l, r,)) => (SomeLR::Left(l), Option::Some(r)),
+            x => (x, Option::None),
+        })
+    }
+
+    /// Returns the left value, inserting the one computed by `f` if absent.
+    pub fn get_or_insert_left_with<F>(&mut self, f: F) -> &mut L
+    where
+        F: FnOnce() -> L,
+    {
+        if !self.is_left() {
+            self.insert_left(f());
+        }
+        match &mut self.0 {
+            SomeLR::Left(l) | SomeLR::Both((l, _,)) => l,
+            SomeLR::Right(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the right value, inserting the one computed by `f` if absent.
+    pub fn get_or_insert_right_with<F>(&mut self, f: F) -> &mut R
+    where
+        F: FnOnce() -> R,
+    {
+        if !self.is_right() {
+            self.insert_right(f());
+        }
+        match &mut self.0 {
+            SomeLR::Right(r) | SomeLR::Both((_, r,)) => r,
+            SomeLR::Left(_) => unreachable!(),
+        }
+    }
+
+    /// Gets the left slot for in-place manipulation. If the slot is vacant,
+    /// the right value is present, so inserting into it always succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let mut s = SomeOf::<u32, char>::new_right('a');
+    /// *s.left_entry().or_insert(1) += 1;
+    /// s.left_entry().and_modify(|l| *l *= 10).or_insert(0);
+    /// assert_eq!(s.split(), (Some(20), Some('a')));
+    ///
+    /// assert_eq!(s.right_entry().remove(), Some('a'));
+    /// assert_eq!(s.left_entry().remove(), None);
+    /// ```
+    pub fn left_entry(&mut self) -> LeftEntry<'_, L, R> {
+        LeftEntry(self)
+    }
+
+    /// Gets the right slot for in-place manipulation.
+    pub fn right_entry(&mut self) -> RightEntry<'_, L, R> {
+        RightEntry(self)
+    }
+
+    /// Rebuilds the value in place from the old one, as there is no empty
+    /// variant to leave behind while moving it out.
+    ///
+    /// If `f` panics the process aborts, since unwinding would drop the old
+    /// value twice: once in `f` and once more with `self`.
+    fn rebuild_with<F, T>(&mut self, f: F) -> T
+    where
+        F: FnOnce(SomeLR<L, R>) -> (SomeLR<L, R>, T),
+    {
+        struct AbortOnUnwind;
+
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                // Panicking while already unwinding aborts the process.
+                panic!("panicked while rebuilding a `SomeOf` in place");
+            }
+        }
+
+        let guard = AbortOnUnwind;
+        // SAFETY: The old value is moved out and overwritten without being
+        // dropped. If `f` unwinds, `guard` aborts before `self` is dropped.
+        let t = unsafe {
+            let (x, t) = f(ptr::read(&self.0));
+            ptr::write(&mut self.0, x);
+            t
+        };
+        mem::forget(guard);
+        t
+    }
+
+    /// Returns the left value, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the left value is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// assert_eq!(SomeOf::<u8, char>::new_left(1).unwrap_left(), 1);
+    ///
+    /// // Use the defaulting accessors when a side may be absent.
+    /// let s = SomeOf::<u8, char>::new_left(1);
+    /// assert_eq!(s.left_or(2), 1);
+    /// assert_eq!(s.right_or_else(|| 'b'), 'b');
+    /// ```
+    ///
+    /// ```should_panic
+    /// use anylr::SomeOf;
+    ///
+    /// // panics with "called `SomeOf::unwrap_left()` on a value of shape `Right`"
+    /// SomeOf::<u8, char>::new_right('a').unwrap_left();
+    /// ```
+    #[track_caller]
+    pub fn unwrap_left(self) -> L {
+        let found = self.shape();
+        match self.split().0 {
+            Option::Some(l) => l,
+            Option::None => shape::unwrap_failed("SomeOf::unwrap_left()", found),
+        }
+    }
+
+    /// Returns the right value, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the right value is absent.
+    #[track_caller]
+    pub fn unwrap_right(self) -> R {
+        let found = self.shape();
+        match self.split().1 {
+            Option::Some(r) => r,
+            Option::None => shape::unwrap_failed("SomeOf::unwrap_right()", found),
+        }
+    }
+
+    /// Returns both values, or panics with the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values is absent.
+    #[track_caller]
+    pub fn unwrap_both(self) -> (L, R) {
+        let found = self.shape();
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => shape::unwrap_failed("SomeOf::unwrap_both()", found),
+        }
+    }
+
+    /// Returns the left value, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the left value is absent.
+    #[track_caller]
+    pub fn expect_left(self, msg: &str) -> L {
+        let found = self.shape();
+        match self.split().0 {
+            Option::Some(l) => l,
+            Option::None => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns the right value, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the right value is absent.
+    #[track_caller]
+    pub fn expect_right(self, msg: &str) -> R {
+        let found = self.shape();
+        match self.split().1 {
+            Option::Some(r) => r,
+            Option::None => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns both values, or panics with `msg` and the shape found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values is absent.
+    #[track_caller]
+    pub fn expect_both(self, msg: &str) -> (L, R) {
+        let found = self.shape();
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => shape::expect_failed(msg, found),
+        }
+    }
+
+    /// Returns the left value, or `default` if absent.
+    pub fn left_or(self, default: L) -> L {
+        self.split().0.unwrap_or(default)
+    }
+
+    /// Returns the left value, or computes one with `f` if absent.
+    pub fn left_or_else<F>(self, f: F) -> L
+    where
+        F: FnOnce() -> L,
+    {
+        self.split().0.unwrap_or_else(f)
+    }
+
+    /// Returns the left value, or `L::default()` if absent.
+    pub fn left_or_default(self) -> L
+    where
+        L: Default,
+    {
+        self.split().0.unwrap_or_default()
+    }
+
+    /// Returns the right value, or `default` if absent.
+    pub fn right_or(self, default: R) -> R {
+        self.split().1.unwrap_or(default)
+    }
+
+    /// Returns the right value, or computes one with `f` if absent.
+    pub fn right_or_else<F>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.split().1.unwrap_or_else(f)
+    }
+
+    /// Returns the right value, or `R::default()` if absent.
+    pub fn right_or_default(self) -> R
+    where
+        R: Default,
+    {
+        self.split().1.unwrap_or_default()
+    }
+
+    /// Returns both values, or `default` unless both are present.
+    pub fn both_or(self, default: (L, R)) -> (L, R) {
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => default,
+        }
+    }
+
+    /// Returns both values, or computes a pair with `f` unless both are
+    /// present.
+    pub fn both_or_else<F>(self, f: F) -> (L, R)
+    where
+        F: FnOnce() -> (L, R),
+    {
+        match self.split() {
+            (Option::Some(l), Option::Some(r)) => (l, r),
+            _ => f(),
+        }
+    }
+}
+
+/// The left slot of a `SomeOf<L, R>`, see `SomeOf::left_entry`.
+pub struct LeftEntry<'a, L, R>(&'a mut SomeOf<L, R>);
+
+impl<'a, L, R> LeftEntry<'a, L, R> {
+    pub fn get(&self) -> Option<&L> {
+        self.0.as_ref().split().0
+    }
+
+    pub fn or_insert(self, default: L) -> &'a mut L {
+        self.0.get_or_insert_left_with(|| default)
+    }
+
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut L
+    where
+        F: FnOnce() -> L,
+    {
+        self.0.get_or_insert_left_with(f)
+    }
+
+    pub fn or_default(self) -> &'a mut L
+    where
+        L: Default,
+    {
+        self.0.get_or_insert_left_with(L::default)
+    }
+
+    /// Applies `f` to the left value if present.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut L),
+    {
+        if let Option::Some(l) = self.0.as_mut().split().0 {
+            f(l)
+        }
+        self
+    }
+
+    /// Sets the left value, returning the previous one if any.
+    pub fn insert(self, l: L) -> Option<L> {
+        self.0.insert_left(l)
+    }
+
+    /// Removes the left value, which only succeeds if the right value is
+    /// also present, see `SomeOf::take_left_in_place`.
+    pub fn remove(self) -> Option<L> {
+        self.0.take_left_in_place()
+    }
+}
+
+/// The right slot of a `SomeOf<L, R>`, see `SomeOf::right_entry`.
+pub struct RightEntry<'a, L, R>(&'a mut SomeOf<L, R>);
+
+impl<'a, L, R> RightEntry<'a, L, R> {
+    pub fn get(&self) -> Option<&R> {
+        self.0.as_ref().split().1
+    }
+
+    pub fn or_insert(self, default: R) -> &'a mut R {
+        self.0.get_or_insert_right_with(|| default)
+    }
+
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut R
+    where
+        F: FnOnce() -> R,
+    {
+        self.0.get_or_insert_right_with(f)
+    }
+
+    pub fn or_default(self) -> &'a mut R
+    where
+        R: Default,
+    {
+        self.0.get_or_insert_right_with(R::default)
+    }
+
+    /// Applies `f` to the right value if present.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut R),
+    {
+        if let Option::Some(r) = self.0.as_mut().split().1 {
+            f(r)
+        }
+        self
+    }
+
+    /// Sets the right value, returning the previous one if any.
+    pub fn insert(self, r: R) -> Option<R> {
+        self.0.insert_right(r)
+    }
+
+    /// Removes the right value, which only succeeds if the left value is
+    /// also present, see `SomeOf::take_right_in_place`.
+    pub fn remove(self) -> Option<R> {
+        self.0.take_right_in_place()
+    }
+}
+
+impl<L, R> SomeOf<&L, &R> {
+    /// Maps a `SomeOf<&L, &R>` to a `SomeOf<L, R>` by cloning the contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let s = SomeOf::new_both(1u8, 'a');
+    /// assert_eq!(s.as_ref().copied().split(), (Some(1), Some('a')));
+    /// ```
+    pub fn cloned(self) -> SomeOf<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(L::clone).map_right(R::clone)
+    }
+
+    /// Maps a `SomeOf<&L, &R>` to a `SomeOf<L, R>` by copying the contents.
+    pub fn copied(self) -> SomeOf<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<L, R> SomeOf<&mut L, &mut R> {
+    /// Maps a `SomeOf<&mut L, &mut R>` to a `SomeOf<L, R>` by cloning the contents.
+    pub fn cloned(self) -> SomeOf<L, R>
+    where
+        L: Clone,
+        R: Clone,
+    {
+        self.map_left(|l| l.clone()).map_right(|r| r.clone())
+    }
+
+    /// Maps a `SomeOf<&mut L, &mut R>` to a `SomeOf<L, R>` by copying the contents.
+    pub fn copied(self) -> SomeOf<L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        self.map_left(|l| *l).map_right(|r| *r)
+    }
+}
+
+impl<A, B, C, D> SomeOf<(A, B), (C, D)> {
+    /// Splits a `SomeOf` of pairs into a pair of `SomeOf`s of the same shape.
+    pub fn unzip(self) -> (SomeOf<A, C>, SomeOf<B, D>) {
+        match self.0 {
+            SomeLR::Left((a, b)) => (SomeOf::new_left(a), SomeOf::new_left(b)),
+            SomeLR::Right((c, d)) => (SomeOf::new_right(c), SomeOf::new_right(d)),
+            SomeLR::Both(((a, b), (c, d))) => (SomeOf::new_both(a, c), SomeOf::new_both(b, d)),
+        }
+    }
+}
+
+impl<A, B, C> SomeOf<(A, B), (A, C)> {
+    /// Distributes `A` over both sides of a `SomeOf<B, C>`, cloning it if
+    /// both sides are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// let s = SomeOf::distribute((1u8, SomeOf::new_both('b', "c")));
+    /// assert_eq!(s.clone().split(), (Some((1, 'b')), Some((1, "c"))));
+    /// let (a, s) = s.factor();
+    /// assert_eq!((a, s.split()), (1, (Some('b'), Some("c"))));
+    /// ```
+    pub fn distribute(pair: (A, SomeOf<B, C>)) -> Self
+    where
+        A: Clone,
+    {
+        let (a, s) = pair;
+        match s.0 {
+            SomeLR::Left(b) => SomeOf::new_left((a, b)),
+            SomeLR::Right(c) => SomeOf::new_right((a, c)),
+            SomeLR::Both((b, c,)) => SomeOf::new_both((a.clone(), b), (a, c)),
+        }
+    }
+
+    /// Factors `A` out of both sides, the inverse of `distribute`. If both
+    /// sides are present, the left `A` is kept and the right one is dropped.
+    pub fn factor(self) -> (A, SomeOf<B, C>) {
+        match self.0 {
+            SomeLR::Left((a, b)) => (a, SomeOf::new_left(b)),
+            SomeLR::Right((a, c)) => (a, SomeOf::new_right(c)),
+            SomeLR::Both(((a, b), (_, c))) => (a, SomeOf::new_both(b, c)),
+        }
+    }
+}
+
+impl<T> SomeOf<T, T> {
+    /// Applies the same function to every present value.
+    pub fn map_both<F, U>(self, mut f: F) -> SomeOf<U, U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self.0 {
+            SomeLR::Left(l) => SomeOf::new_left(f(l)),
+            SomeLR::Right(r) => SomeOf::new_right(f(r)),
+            SomeLR::Both((l, r,)) => SomeOf::new_both(f(l), f(r)),
+        }
+    }
+
+    /// Collapses the value into a single `T`, combining both values with `f`
+    /// if both are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::SomeOf;
+    ///
+    /// assert_eq!(SomeOf::new_both(1, 2).reduce(|l, r| l + r), 3);
+    /// assert_eq!(SomeOf::<i32, i32>::new_right(2).reduce(|l, r| l + r), 2);
+    /// ```
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match self.0 {
+            SomeLR::Left(x) | SomeLR::Right(x) => x,
+            SomeLR::Both((l, r,)) => f(l, r),
+        }
+    }
+
+    /// Returns the maximum of the present values with respect to `compare`,
+    /// or the right one if they are equal.
+    pub fn max_by<F>(self, compare: F) -> T
+    where
+        F: FnOnce(&T, &T) -> Ordering,
+    {
+        self.reduce(|l, r| match compare(&l, &r) {
+            Ordering::Greater => l,
+            _ => r,
+        })
+    }
+
+    /// Returns the minimum of the present values with respect to `compare`,
+    /// or the left one if they are equal.
+    pub fn min_by<F>(self, compare: F) -> T
+    where
+        F: FnOnce(&T, &T) -> Ordering,
+    {
+        self.reduce(|l, r| match compare(&l, &r) {
+            Ordering::Greater => r,
+            _ => l,
+        })
+    }
+
+    /// Iterates over references to the present values, from left to right.
+    pub fn iter(&self) -> iter::Chain<option::IntoIter<&T>, option::IntoIter<&T>> {
+        self.as_ref().into_iter()
+    }
+
+    /// Iterates over mutable references to the present values, from left to
+    /// right.
+    pub fn iter_mut(&mut self) -> iter::Chain<option::IntoIter<&mut T>, option::IntoIter<&mut T>> {
+        self.as_mut().into_iter()
+    }
+
+    /// Puts the left value, if any, in the first slot and the right value, if
+    /// any, in the second slot.
+    pub fn into_array(self) -> [Option<T>; 2] {
+        let (l, r) = self.split();
+        [l, r]
+    }
+}
+
+impl<T> IntoIterator for SomeOf<T, T> {
+    type Item = T;
+    type IntoIter = iter::Chain<option::IntoIter<T>, option::IntoIter<T>>;
+
+    /// Iterates over the present values, from left to right.
+    fn into_iter(self) -> Self::IntoIter {
+        let (l, r) = self.split();
+        l.into_iter().chain(r)
+    }
+}
+
+/// Writes every chunk to every present side, like the impl for `Any`.
+impl<L: fmt::Write, R: fmt::Write> fmt::Write for SomeOf<L, R> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_each_mut(|l| l.write_str(s), |r| r.write_str(s))
+            .map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_each_mut(|l| l.write_char(c), |r| r.write_char(c))
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// Extends every present side with the same items, like the impl for `Any`.
+impl<T: Clone, L: Extend<T>, R: Extend<T>> Extend<T> for SomeOf<L, R> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match &mut self.0 {
+            SomeLR::Left(l) => l.extend(iter),
+            SomeLR::Right(r) => r.extend(iter),
+            SomeLR::Both((l, r,)) => {
+                for x in iter {
+                    l.extend(Option::Some(x.clone()));
+                    r.extend(Option::Some(x));
+                }
+            }
+        }
+    }
+}
+
+/// Shows the present values, separated by `"; "` when both are present.
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for SomeOf<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            SomeLR::Left(l) => l.fmt(f),
+            SomeLR::Right(r) => r.fmt(f),
+            SomeLR::Both((l, r,)) => write!(f, "{l}; {r}"),
+        }
+    }
+}
+
+/// Lets `SomeOf` report which of two fallible operations failed, as returned
+/// by `try_each_mut`.
+///
+/// # Examples
+///
+/// ```
+/// use anylr::SomeOf;
+///
+/// let e = SomeOf::new_both("left failed", "right failed");
+/// assert_eq!(e.to_string(), "left failed; right failed");
+///
+/// let e: SomeOf<core::fmt::Error, core::fmt::Error> = SomeOf::new_right(core::fmt::Error);
+/// let _: &dyn core::error::Error = &e;
+/// ```
+impl<L: Error, R: Error> Error for SomeOf<L, R> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.0 {
+            SomeLR::Left(l) => l.source(),
+            SomeLR::Right(r) => r.source(),
+            SomeLR::Both(_) => Option::None,
+        }
+    }
+}
+
+impl<L: Copy, R: Copy> SomeOf<L, R> {
+    /// The same as `split`, but usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anylr::{Shape, SomeOf};
+    ///
+    /// const S: SomeOf<u8, char> = SomeOf::new_left(1);
+    /// const R: SomeOf<char, u8> = S.to_reversed();
+    /// const _: () = assert!(S.is_left() && !S.is_both());
+    /// const _: () = assert!(R.is_right() && !R.contains_left());
+    /// const _: () = assert!(matches!(R.shape(), Shape::Right));
+    /// const SPLIT: (Option<char>, Option<u8>) = R.to_split();
+    /// assert_eq!(SPLIT, (None, Some(1)));
+    /// ```
+    pub const fn to_split(self) -> (Option<L>, Option<R>) {
+        match self.0 {
+            SomeLR::Left(l) => (Option::Some(l), Option::None),
+            SomeLR::Right(r) => (Option::None, Option::Some(r)),
+            SomeLR::Both((l, r,)) => (Option::Some(l), Option::Some(r)),
+        }
+    }
+
+    /// The same as `reverse`, but usable in const contexts.
+    pub const fn to_reversed(self) -> SomeOf<R, L> {
+        SomeOf(match self.0 {
+            SomeLR::Left(l) => SomeLR::Right(l),
+            SomeLR::Right(r) => SomeLR::Left(r),
+            SomeLR::Both((l, r,)) => SomeLR::Both((r, l,)),
+        })
+    }
+}
+
+/// The same as `SomeOf::parse_all`.
+impl<L: FromStr, R: FromStr> FromStr for SomeOf<L, R> {
+    type Err = SomeOf<L::Err, R::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SomeOf::parse_all(s)
+    }
 }
 
 impl<L, R> From<Either<L, R>> for SomeOf<L, R> {
@@ -111,6 +1088,13 @@ impl<L, R> From<(L, R,)> for SomeOf<L, R> {
     }
 }
 
+impl<T> From<[T; 2]> for SomeOf<T, T> {
+    fn from(value: [T; 2]) -> Self {
+        let [l, r] = value;
+        SomeOf::new_both(l, r)
+    }
+}
+
 impl<T, E> From<Result<T, E>> for SomeOf<T, E> {
     fn from(value: Result<T, E>) -> Self {
         match value {
@@ -120,19 +1104,82 @@ impl<T, E> From<Result<T, E>> for SomeOf<T, E> {
     }
 }
 
+/// Fails with the original value if it is `Neither`.
 impl<L, R> TryFrom<Any<L, R>> for SomeOf<L, R> {
     type Error = Any<L, R>;
 
     fn try_from(value: Any<L, R>) -> Result<Self, Any<L, R>> {
-        match value.split() {
+        match value.into_inner() {
+            AnyLR::Neither => Result::Err(Any::new_neither()),
+            AnyLR::Left(l) => Result::Ok(SomeOf::new_left(l)),
+            AnyLR::Right(r) => Result::Ok(SomeOf::new_right(r)),
+            AnyLR::Both((l, r,)) => Result::Ok(SomeOf::new_both(l, r)),
+        }
+    }
+}
+
+/// `Some` becomes `Left`. Fails with `None`.
+impl<L, R> TryFrom<Option<L>> for SomeOf<L, R> {
+    type Error = Option<L>;
+
+    fn try_from(value: Option<L>) -> Result<Self, Self::Error> {
+        value.map(SomeOf::new_left).ok_or(Option::None)
+    }
+}
+
+/// Fails with the original value if both are `None`.
+impl<L, R> TryFrom<(Option<L>, Option<R>,)> for SomeOf<L, R> {
+    type Error = (Option<L>, Option<R>,);
+
+    fn try_from(value: (Option<L>, Option<R>,)) -> Result<Self, Self::Error> {
+        match value {
             (Option::Some(l), Option::Some(r)) => Result::Ok(SomeOf::new_both(l, r)),
             (Option::Some(l), Option::None) => Result::Ok(SomeOf::new_left(l)),
             (Option::None, Option::Some(r)) => Result::Ok(SomeOf::new_right(r)),
-            _ => Result::Err(Any::new_neither()),
+            (Option::None, Option::None) => Result::Err((Option::None, Option::None)),
         }
     }
 }
 
+impl<L, R> From<SomeOf<L, R>> for (Option<L>, Option<R>,) {
+    fn from(value: SomeOf<L, R>) -> Self {
+        value.split()
+    }
+}
+
+/// Fails with the original value unless it is `Both`.
+impl<L, R> TryFrom<SomeOf<L, R>> for (L, R,) {
+    type Error = SomeOf<L, R>;
+
+    fn try_from(value: SomeOf<L, R>) -> Result<Self, Self::Error> {
+        match value.0 {
+            SomeLR::Both(pair) => Result::Ok(pair),
+            x => Result::Err(SomeOf(x)),
+        }
+    }
+}
+
+/// Fails with the original value unless it is `Left`.
+impl<L, R> TryFrom<SomeOf<L, R>> for Option<L> {
+    type Error = SomeOf<L, R>;
+
+    fn try_from(value: SomeOf<L, R>) -> Result<Self, Self::Error> {
+        match value.0 {
+            SomeLR::Left(l) => Result::Ok(Option::Some(l)),
+            x => Result::Err(SomeOf(x)),
+        }
+    }
+}
+
+/// Fails with the original value if it is `Both`.
+impl<T, E> TryFrom<SomeOf<T, E>> for Result<T, E> {
+    type Error = SomeOf<T, E>;
+
+    fn try_from(value: SomeOf<T, E>) -> Result<Self, Self::Error> {
+        Either::try_from(value).map(Result::from)
+    }
+}
+
 impl<L, R> TrReverseLeftRight for SomeOf<L, R> {
     type Lt = L;
     type Rt = R;
@@ -201,6 +1248,11 @@ impl<L, R> TrAnyLeftRight for SomeOf<L, R> {
     {
         SomeOf::as_mut(self)
     }
+
+    #[inline]
+    fn shape(&self) -> Shape {
+        SomeOf::shape(self)
+    }
 }
 
 impl<L: Copy, R: Copy> Copy for SomeOf<L, R>
@@ -253,26 +1305,26 @@ impl<L, R> SomeLR<L, R> {
         }
     }
 
-    /// The variant is `SomeOf::Left` or `SomeOf::Both`
-    pub(crate) fn is_left(&self) -> bool {
+    pub const fn shape(&self) -> Shape {
         match self {
-            SomeLR::Left(_) => true,
-            SomeLR::Both(_) => true,
-            _ => false,
+            SomeLR::Left(_) => Shape::Left,
+            SomeLR::Right(_) => Shape::Right,
+            SomeLR::Both(_) => Shape::Both,
         }
     }
 
+    /// The variant is `SomeOf::Left` or `SomeOf::Both`
+    pub(crate) const fn is_left(&self) -> bool {
+        matches!(self, SomeLR::Left(_) | SomeLR::Both(_))
+    }
+
     /// The variant is `SomeOf::Right` or `SomeOf::Both`
-    pub(crate) fn is_right(&self) -> bool {
-        match self {
-            SomeLR::Right(_) => true,
-            SomeLR::Both(_) => true,
-            _ => false,
-        }
+    pub(crate) const fn is_right(&self) -> bool {
+        matches!(self, SomeLR::Right(_) | SomeLR::Both(_))
     }
 
     /// The variant is just `SomeOf::Both`
-    pub(crate) fn is_both(&self) -> bool {
+    pub(crate) const fn is_both(&self) -> bool {
         matches!(self, SomeLR::Both(_))
     }
 }
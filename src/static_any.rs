@@ -0,0 +1,295 @@
+//! An `Any` whose shape is part of its type.
+//!
+//! `StaticAny<L, R, SL, SR>` stores the left value only if `SL` is `Present`
+//! and the right value only if `SR` is `Present`. Setting or taking a side
+//! changes the type, so accessors such as `left` exist only when the side is
+//! known to be there, and no `Option` is checked at runtime. Use `into_any`,
+//! `into_some_of` or `into_pair` to hand the value to code that works with the
+//! dynamic types.
+//!
+//! # Examples
+//!
+//! ```
+//! use anylr::{Shape, StaticAny};
+//!
+//! let a = StaticAny::<u8, &str>::new();
+//! assert_eq!(a.shape(), Shape::Neither);
+//!
+//! let a = a.set_right("name").set_left(1);
+//! assert_eq!(*a.left() + 1, 2);
+//! assert_eq!(a.shape(), Shape::Both);
+//!
+//! let (l, a) = a.take_left();
+//! assert_eq!(l, 1);
+//! assert_eq!(a.into_some_of().unwrap_right(), "name");
+//! ```
+//!
+//! Reading a side that is not there does not compile:
+//!
+//! ```compile_fail
+//! use anylr::StaticAny;
+//!
+//! let a = StaticAny::<u8, u8>::new_right(1);
+//! let _ = a.left();
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use crate::{Any, Shape, SomeOf};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Absent {}
+    impl Sealed for super::Present {}
+}
+
+/// Tells whether a side of a `StaticAny` holds a value.
+///
+/// It is sealed: `Absent` and `Present` are the only implementations, so the
+/// accessors that rely on them cannot be fooled by a third kind of slot.
+///
+/// ```compile_fail
+/// use core::marker::PhantomData;
+/// use anylr::static_any::TrSlot;
+///
+/// enum Maybe {}
+///
+/// impl TrSlot for Maybe {
+///     const PRESENT: bool = true;
+///
+///     type Store<T> = PhantomData<T>;
+///
+///     fn into_option<T>(_: PhantomData<T>) -> Option<T> { None }
+///
+///     fn as_option<T>(_: &PhantomData<T>) -> Option<&T> { None }
+/// }
+/// ```
+pub trait TrSlot: sealed::Sealed {
+    const PRESENT: bool;
+
+    type Store<T>;
+
+    fn into_option<T>(store: Self::Store<T>) -> Option<T>;
+
+    fn as_option<T>(store: &Self::Store<T>) -> Option<&T>;
+}
+
+/// The side holds no value.
+pub enum Absent {}
+
+/// The side holds a value.
+pub enum Present {}
+
+impl TrSlot for Absent {
+    const PRESENT: bool = false;
+
+    type Store<T> = PhantomData<T>;
+
+    #[inline]
+    fn into_option<T>(_: PhantomData<T>) -> Option<T> {
+        Option::None
+    }
+
+    #[inline]
+    fn as_option<T>(_: &PhantomData<T>) -> Option<&T> {
+        Option::None
+    }
+}
+
+impl TrSlot for Present {
+    const PRESENT: bool = true;
+
+    type Store<T> = T;
+
+    #[inline]
+    fn into_option<T>(store: T) -> Option<T> {
+        Option::Some(store)
+    }
+
+    #[inline]
+    fn as_option<T>(store: &T) -> Option<&T> {
+        Option::Some(store)
+    }
+}
+
+pub struct StaticAny<L, R, SL: TrSlot = Absent, SR: TrSlot = Absent> {
+    left: SL::Store<L>,
+    right: SR::Store<R>,
+}
+
+impl<L, R> StaticAny<L, R> {
+    pub const fn new() -> Self {
+        StaticAny {
+            left: PhantomData,
+            right: PhantomData,
+        }
+    }
+}
+
+impl<L, R> Default for StaticAny<L, R> {
+    fn default() -> Self {
+        StaticAny::new()
+    }
+}
+
+impl<L, R> StaticAny<L, R, Present, Absent> {
+    pub const fn new_left(l: L) -> Self {
+        StaticAny {
+            left: l,
+            right: PhantomData,
+        }
+    }
+}
+
+impl<L, R> StaticAny<L, R, Absent, Present> {
+    pub const fn new_right(r: R) -> Self {
+        StaticAny {
+            left: PhantomData,
+            right: r,
+        }
+    }
+}
+
+impl<L, R> StaticAny<L, R, Present, Present> {
+    pub const fn new_both(l: L, r: R) -> Self {
+        StaticAny { left: l, right: r }
+    }
+
+    pub fn into_pair(self) -> (L, R,) {
+        (self.left, self.right,)
+    }
+}
+
+impl<L, R, SL: TrSlot, SR: TrSlot> StaticAny<L, R, SL, SR> {
+    /// The shape, which is known from the type alone.
+    pub const fn shape(&self) -> Shape {
+        Shape::new(SL::PRESENT, SR::PRESENT)
+    }
+
+    /// Stores the left value, dropping the old one if there was one.
+    pub fn set_left(self, l: L) -> StaticAny<L, R, Present, SR> {
+        StaticAny {
+            left: l,
+            right: self.right,
+        }
+    }
+
+    /// Stores the right value, dropping the old one if there was one.
+    pub fn set_right(self, r: R) -> StaticAny<L, R, SL, Present> {
+        StaticAny {
+            left: self.left,
+            right: r,
+        }
+    }
+
+    /// Drops the left value, if there is one.
+    pub fn clear_left(self) -> StaticAny<L, R, Absent, SR> {
+        StaticAny {
+            left: PhantomData,
+            right: self.right,
+        }
+    }
+
+    /// Drops the right value, if there is one.
+    pub fn clear_right(self) -> StaticAny<L, R, SL, Absent> {
+        StaticAny {
+            left: self.left,
+            right: PhantomData,
+        }
+    }
+
+    pub fn as_any(&self) -> Any<&L, &R> {
+        (SL::as_option(&self.left), SR::as_option(&self.right),).into()
+    }
+
+    pub fn into_any(self) -> Any<L, R> {
+        (SL::into_option(self.left), SR::into_option(self.right),).into()
+    }
+}
+
+impl<L, R, SR: TrSlot> StaticAny<L, R, Present, SR> {
+    pub const fn left(&self) -> &L {
+        &self.left
+    }
+
+    pub fn left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Moves the left value out, leaving its side absent.
+    pub fn take_left(self) -> (L, StaticAny<L, R, Absent, SR>,) {
+        let rest = StaticAny {
+            left: PhantomData,
+            right: self.right,
+        };
+        (self.left, rest,)
+    }
+
+    pub fn into_some_of(self) -> SomeOf<L, R> {
+        match SR::into_option(self.right) {
+            Option::Some(r) => SomeOf::new_both(self.left, r),
+            Option::None => SomeOf::new_left(self.left),
+        }
+    }
+}
+
+impl<L, R> StaticAny<L, R, Absent, Present> {
+    pub fn into_some_of(self) -> SomeOf<L, R> {
+        SomeOf::new_right(self.right)
+    }
+}
+
+impl<L, R, SL: TrSlot> StaticAny<L, R, SL, Present> {
+    pub const fn right(&self) -> &R {
+        &self.right
+    }
+
+    pub fn right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Moves the right value out, leaving its side absent.
+    pub fn take_right(self) -> (R, StaticAny<L, R, SL, Absent>,) {
+        let rest = StaticAny {
+            left: self.left,
+            right: PhantomData,
+        };
+        (self.right, rest,)
+    }
+}
+
+impl<L, R, SL: TrSlot, SR: TrSlot> From<StaticAny<L, R, SL, SR>> for Any<L, R> {
+    fn from(value: StaticAny<L, R, SL, SR>) -> Self {
+        value.into_any()
+    }
+}
+
+impl<L, R, SL, SR> Clone for StaticAny<L, R, SL, SR>
+where
+    SL: TrSlot,
+    SR: TrSlot,
+    SL::Store<L>: Clone,
+    SR::Store<R>: Clone,
+{
+    fn clone(&self) -> Self {
+        StaticAny {
+            left: self.left.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<L, R, SL, SR> Copy for StaticAny<L, R, SL, SR>
+where
+    SL: TrSlot,
+    SR: TrSlot,
+    SL::Store<L>: Copy,
+    SR::Store<R>: Copy,
+{ }
+
+impl<L: fmt::Debug, R: fmt::Debug, SL: TrSlot, SR: TrSlot> fmt::Debug for StaticAny<L, R, SL, SR> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StaticAny").field(&self.as_any()).finish()
+    }
+}
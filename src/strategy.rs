@@ -0,0 +1,198 @@
+//! [`proptest`] strategies and `Arbitrary` implementations for `Any<L, R>`,
+//! `SomeOf<L, R>` and `Either<L, R>`.
+//!
+//! Every strategy lists its shapes from the simplest to the most complex, so
+//! a failing case shrinks towards simpler shapes (e.g. `Both` to `Left`)
+//! before the payloads themselves are shrunk.
+//!
+//! # Examples
+//!
+//! ```
+//! use anylr::{strategy, Any};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn reverse_twice(x in any::<Any<u8, i16>>()) {
+//!         let (l, r) = x.split();
+//!         prop_assert_eq!(x.reverse().reverse().split(), (l, r));
+//!     }
+//!
+//!     fn small_left(x in strategy::some_of(0u8..8, any::<i16>())) {
+//!         prop_assert!(x.split().0.is_none_or(|l| l < 8));
+//!     }
+//! }
+//! # reverse_twice();
+//! # small_left();
+//! ```
+
+use core::fmt::Debug;
+
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, LazyJust, Strategy},
+};
+
+use crate::{Any, Either, SomeOf};
+
+/// Creates a strategy for `Any<L, R>` from the strategies of each side.
+///
+/// The generated shapes are, from the simplest, `Neither`, `Left`, `Right`
+/// and `Both`.
+pub fn any_of<SL, SR>(
+    left: SL,
+    right: SR,
+) -> impl Strategy<Value = Any<SL::Value, SR::Value>>
+where
+    SL: Strategy + Clone,
+    SR: Strategy + Clone,
+{
+    prop_oneof![
+        LazyJust::new(Any::new_neither),
+        left.clone().prop_map(Any::new_left),
+        right.clone().prop_map(Any::new_right),
+        (left, right).prop_map(|(l, r)| Any::new_both(l, r)),
+    ]
+}
+
+/// Creates a strategy for `SomeOf<L, R>` from the strategies of each side.
+///
+/// The generated shapes are, from the simplest, `Left`, `Right` and `Both`.
+pub fn some_of<SL, SR>(
+    left: SL,
+    right: SR,
+) -> impl Strategy<Value = SomeOf<SL::Value, SR::Value>>
+where
+    SL: Strategy + Clone,
+    SR: Strategy + Clone,
+{
+    prop_oneof![
+        left.clone().prop_map(SomeOf::new_left),
+        right.clone().prop_map(SomeOf::new_right),
+        (left, right).prop_map(|(l, r)| SomeOf::new_both(l, r)),
+    ]
+}
+
+/// Creates a strategy for `Either<L, R>` from the strategies of each side.
+pub fn either<SL, SR>(
+    left: SL,
+    right: SR,
+) -> impl Strategy<Value = Either<SL::Value, SR::Value>>
+where
+    SL: Strategy,
+    SR: Strategy,
+{
+    prop_oneof![
+        left.prop_map(Either::new_left),
+        right.prop_map(Either::new_right),
+    ]
+}
+
+impl<L, R> Arbitrary for Any<L, R>
+where
+    L: Arbitrary + Debug + 'static,
+    R: Arbitrary + Debug + 'static,
+{
+    type Parameters = (L::Parameters, R::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (l, r) = args;
+        any_of(any_with::<L>(l).boxed(), any_with::<R>(r).boxed()).boxed()
+    }
+}
+
+impl<L, R> Arbitrary for SomeOf<L, R>
+where
+    L: Arbitrary + Debug + 'static,
+    R: Arbitrary + Debug + 'static,
+{
+    type Parameters = (L::Parameters, R::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (l, r) = args;
+        some_of(any_with::<L>(l).boxed(), any_with::<R>(r).boxed()).boxed()
+    }
+}
+
+impl<L, R> Arbitrary for Either<L, R>
+where
+    L: Arbitrary + Debug + 'static,
+    R: Arbitrary + Debug + 'static,
+{
+    type Parameters = (L::Parameters, R::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (l, r) = args;
+        either(any_with::<L>(l).boxed(), any_with::<R>(r).boxed()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use proptest::prelude::*;
+
+    use crate::{abs::TrAnyLeftRight, strategy, Any, Either, SomeOf};
+
+    /// The laws of `TrAnyLeftRight` that hold for every shape.
+    fn check_laws<T>(x: T) -> Result<(), TestCaseError>
+    where
+        T: TrAnyLeftRight + Clone,
+        T::Lt: Clone + PartialEq + Debug,
+        T::Rt: Clone + PartialEq + Debug,
+    {
+        let (l, r) = x.clone().split();
+        prop_assert_eq!(x.clone().pick_left(), l.clone());
+        prop_assert_eq!(x.clone().pick_right(), r.clone());
+        prop_assert_eq!(x.contains_left(), l.is_some());
+        prop_assert_eq!(x.contains_right(), r.is_some());
+        prop_assert_eq!(x.as_ref().split(), (l.as_ref(), r.as_ref()));
+        prop_assert_eq!(x.clone().map_left(|v| v).split(), (l.clone(), r.clone()));
+        prop_assert_eq!(x.clone().map_right(|v| v).split(), (l.clone(), r.clone()));
+        prop_assert_eq!(x.clone().take_left().split().0, l.clone());
+        prop_assert_eq!(
+            x.clone().take_left().split().1.and_then(|rest| rest.pick_left()),
+            Option::None,
+        );
+        prop_assert_eq!(
+            x.clone().take_right().split().1.and_then(|rest| rest.pick_right()),
+            Option::None,
+        );
+        Result::Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn any_laws(x in any::<Any<u8, i16>>()) {
+            check_laws(x)?;
+            let (l, r) = x.split();
+            prop_assert_eq!(x.reverse().split(), (r, l));
+            prop_assert_eq!(x.reverse().reverse().split(), (l, r));
+        }
+
+        #[test]
+        fn some_of_laws(x in strategy::some_of(0u8..8, any::<i16>())) {
+            check_laws(x)?;
+            prop_assert!(x.is_left() || x.is_right());
+        }
+
+        #[test]
+        fn either_laws(x in any::<Either<u8, i16>>()) {
+            check_laws(x)?;
+            prop_assert!(x.is_left() != x.is_right());
+        }
+    }
+
+    #[test]
+    fn some_of_arbitrary_is_never_empty() {
+        let mut runner = proptest::test_runner::TestRunner::default();
+        for _ in 0..64 {
+            let x = any::<SomeOf<u8, u8>>().new_tree(&mut runner).unwrap().current();
+            assert!(x.is_left() || x.is_right());
+        }
+    }
+}